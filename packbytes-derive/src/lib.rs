@@ -4,10 +4,12 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
+use quote::{format_ident, quote, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
-use syn::{Fields, Ident, Item, ItemEnum, ItemStruct, LitInt, Meta, Type};
+use syn::{
+    Attribute, Expr, Fields, Ident, Item, ItemEnum, ItemStruct, LitInt, Meta, MetaNameValue, Type,
+};
 
 type UnitFields = Punctuated<syn::Field, Comma>;
 
@@ -22,6 +24,12 @@ type UnitFields = Punctuated<syn::Field, Comma>;
 /// in the little endian order.
 /// You can change this by setting the attribute `#[packbytes(be)]` for big endian or `#[packbytes(ne)]`
 /// for the platform native endian.
+///
+/// # Scaled fields
+/// A floating point field can be stored in bytes as a scaled and biased integer by setting
+/// the attribute `#[packbytes(repr = i16, scale = 0.01, offset = -40.0)]` on it. The value of
+/// the field is then `raw * scale + offset`, where `raw` is the integer of the type `repr`.
+/// The scale defaults to `1.0` and the offset to `0.0`.
 #[proc_macro_derive(FromBytes, attributes(packbytes))]
 pub fn frombytes_derive(input: TokenStream) -> TokenStream {
     let item: ItemStruct =
@@ -55,11 +63,13 @@ pub fn frombytes_derive(input: TokenStream) -> TokenStream {
             },
             |n| quote!(#n),
         );
-        (name, field.ty)
+        (name, field.ty, get_scaled(&field.attrs))
     });
 
-    let field_sizes = fields.clone().map(|(_, ty)| {
-        if let Type::Array(arr) = ty {
+    let field_sizes = fields.clone().map(|(_, ty, scaled)| {
+        if let Some(Scaled { repr, .. }) = scaled {
+            quote! { <<#repr as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
+        } else if let Type::Array(arr) = ty {
             let len = arr.len;
             let aty = arr.elem;
             quote! { (#len) * <<#aty as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
@@ -68,26 +78,31 @@ pub fn frombytes_derive(input: TokenStream) -> TokenStream {
         }
     });
 
-    let from_fields = |method| {
-        fields.clone().map(move |(name, ty)| {
-        if let Type::Array(arr) = ty {
+    let from_fields = |method, array_method| {
+        fields.clone().map(move |(name, ty, scaled)| {
+        if let Some(scaled) = scaled {
+            let decoded = scaled.decode(&ty, &method);
+            quote!(#name: #decoded)
+        } else if let Type::Array(arr) = ty {
             let len = arr.len;
             let aty = arr.elem;
             quote! {
                 #name: {
                     let size = <<#aty as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
-                    let val = ::core::array::from_fn(|j| {
-                        <#aty as ::packbytes::FromBytes>::#method(bytes[i+j*size..i+(j+1)*size].try_into().unwrap())
-                    });
+                    let val = <#aty as ::packbytes::FromBytes>::#array_method(&bytes[i..i+(#len)*size]);
                     i += (#len) * size;
                     val
                 }
             }
         } else {
+            let from_slice = bytes_from_slice(
+                quote!(<#ty as ::packbytes::FromBytes>::Bytes),
+                quote!(bytes[i..i+size]),
+            );
             quote! {
                 #name: {
                     let size = <<#ty as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
-                    let val = <#ty as ::packbytes::FromBytes>::#method(bytes[i..i+size].try_into().unwrap());
+                    let val = <#ty as ::packbytes::FromBytes>::#method(#from_slice);
                     i += size;
                     val
                 }
@@ -95,8 +110,8 @@ pub fn frombytes_derive(input: TokenStream) -> TokenStream {
         }
     })
     };
-    let from_le_fields = from_fields(quote!(from_le_bytes));
-    let from_be_fields = from_fields(quote!(from_be_bytes));
+    let from_le_fields = from_fields(quote!(from_le_bytes), quote!(decode_le_array));
+    let from_be_fields = from_fields(quote!(from_be_bytes), quote!(decode_be_array));
 
     let tokens = quote! {
         impl #generics ::packbytes::FromBytes for #name #generics {
@@ -135,6 +150,15 @@ pub fn frombytes_derive(input: TokenStream) -> TokenStream {
 /// # Fieldless enums
 /// The trait is implemented for fieldless enums by converting the numerical value (of the type
 /// set by the `repr` attribute on the enum) to bytes.
+///
+/// # Scaled fields
+/// A floating point field can be stored in bytes as a scaled and biased integer by setting
+/// the attribute `#[packbytes(repr = i16, scale = 0.01, offset = -40.0)]` on it. The value of
+/// the field is then `raw * scale + offset`, where `raw` is the integer of the type `repr`.
+/// The scale defaults to `1.0` and the offset to `0.0`.
+///
+/// When encoding, the value is rounded to the nearest integer. If it does not fit into `repr`,
+/// it is saturated to its minimum or maximum. Derive `TryToBytes` instead to get an error.
 #[proc_macro_derive(ToBytes, attributes(packbytes))]
 pub fn tobytes_derive(input: TokenStream) -> TokenStream {
     match syn::parse::<Item>(input) {
@@ -173,11 +197,13 @@ fn tobytes_struct_derive(item: ItemStruct) -> TokenStream {
             },
             |n| quote!(#n),
         );
-        (name, field.ty)
+        (name, field.ty, get_scaled(&field.attrs))
     });
 
-    let field_sizes = fields.clone().map(|(_, ty)| {
-        if let Type::Array(arr) = ty {
+    let field_sizes = fields.clone().map(|(_, ty, scaled)| {
+        if let Some(Scaled { repr, .. }) = scaled {
+            quote! { <<#repr as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
+        } else if let Type::Array(arr) = ty {
             let len = arr.len;
             let aty = arr.elem;
             quote! { (#len) * <<#aty as ::packbytes::ToBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
@@ -186,29 +212,39 @@ fn tobytes_struct_derive(item: ItemStruct) -> TokenStream {
         }
     });
 
-    let to_fields = |method| {
-        fields.clone().map(move |(name, ty)| {
-        if let Type::Array(arr) = ty {
+    let to_fields = |method, slice_method| {
+        fields.clone().map(move |(name, ty, scaled)| {
+        if let Some(scaled) = scaled {
+            let repr = &scaled.repr;
+            let rounded = scaled.encode_rounded(&name);
+            quote! {
+                let size = <<#repr as ::packbytes::ToBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
+                let raw = { #rounded } as #repr;
+                bytes[i..i+size].copy_from_slice(&<#repr as ::packbytes::ToBytes>::#method(raw));
+                i += size;
+            }
+        } else if let Type::Array(arr) = ty {
             let len = arr.len;
             let aty = arr.elem;
             quote! {
                 let size = <<#aty as ::packbytes::ToBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
-                for j in 0..(#len) {
-                    bytes[i+j*size..i+(j+1)*size].copy_from_slice(&<#aty as ::packbytes::ToBytes>::#method(self.#name[j]));
-                }
+                <#aty as ::packbytes::ToBytes>::#slice_method(&self.#name, &mut bytes[i..i+(#len)*size])
+                    .expect("field slice has the exact size");
                 i += (#len)*size;
             }
         } else {
             quote! {
                 let size = <<#ty as ::packbytes::ToBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
-                bytes[i..i+size].copy_from_slice(&<#ty as ::packbytes::ToBytes>::#method(self.#name));
+                bytes[i..i+size].copy_from_slice(::core::convert::AsRef::<[u8]>::as_ref(
+                    &<#ty as ::packbytes::ToBytes>::#method(self.#name)
+                ));
                 i += size;
             }
         }
     })
     };
-    let to_le_fields = to_fields(quote!(to_le_bytes));
-    let to_be_fields = to_fields(quote!(to_be_bytes));
+    let to_le_fields = to_fields(quote!(to_le_bytes), quote!(encode_le_slice));
+    let to_be_fields = to_fields(quote!(to_be_bytes), quote!(encode_be_slice));
 
     let tokens = quote! {
         impl #generics ::packbytes::ToBytes for #name #generics {
@@ -293,6 +329,12 @@ fn tobytes_enum_derive(item: ItemEnum) -> TokenStream {
 /// The trait is implementing for fieldless enums by first converting bytes to a numerical value
 /// (of the type set by the `repr` attribute on the enum) and then comparing it to the values of all variants.
 ///
+/// # Scaled fields
+/// A floating point field can be stored in bytes as a scaled and biased integer by setting
+/// the attribute `#[packbytes(repr = i16, scale = 0.01, offset = -40.0)]` on it. The value of
+/// the field is then `raw * scale + offset`, where `raw` is the integer of the type `repr`.
+/// The scale defaults to `1.0` and the offset to `0.0`.
+///
 /// # Errors
 /// By default, the error type is `packbytes::errors::InvalidData`. You can provide a custom error
 /// type with the `packbytes_error` attribute.
@@ -376,11 +418,13 @@ fn tryfrombytes_struct_derive(item: ItemStruct) -> TokenStream {
             },
             |n| quote!(#n),
         );
-        (name, field.ty)
+        (name, field.ty, get_scaled(&field.attrs))
     });
 
-    let field_sizes = fields.clone().map(|(_, ty)| {
-        if let Type::Array(arr) = ty {
+    let field_sizes = fields.clone().map(|(_, ty, scaled)| {
+        if let Some(Scaled { repr, .. }) = scaled {
+            quote! { <<#repr as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
+        } else if let Type::Array(arr) = ty {
             let len = arr.len;
             let aty = arr.elem;
             quote! { (#len) * <<#aty as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
@@ -390,26 +434,31 @@ fn tryfrombytes_struct_derive(item: ItemStruct) -> TokenStream {
     });
 
     // TODO: switch to `core::array::try_from_fn` once stabilised
-    let from_fields = |method, regular_method| {
-        fields.clone().map(move |(name, ty)| {
-        if let Type::Array(arr) = ty {
+    let from_fields = |method, regular_method, array_method| {
+        fields.clone().map(move |(name, ty, scaled)| {
+        if let Some(scaled) = scaled {
+            let decoded = scaled.decode(&ty, &regular_method);
+            quote!(#name: #decoded)
+        } else if let Type::Array(arr) = ty {
             let len = arr.len;
             let aty = arr.elem;
             quote! {
                 #name: {
                     let size = <<#aty as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
-                    let val = ::core::array::from_fn(|j| {
-                        <#aty as ::packbytes::FromBytes>::#regular_method(bytes[i+j*size..i+(j+1)*size].try_into().unwrap())
-                    });
+                    let val = <#aty as ::packbytes::FromBytes>::#array_method(&bytes[i..i+(#len)*size]);
                     i += (#len) * size;
                     val
                 }
             }
         } else {
+            let from_slice = bytes_from_slice(
+                quote!(<#ty as ::packbytes::TryFromBytes>::Bytes),
+                quote!(bytes[i..i+size]),
+            );
             quote! {
                 #name: {
                     let size = <<#ty as ::packbytes::TryFromBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
-                    let val = <#ty as ::packbytes::TryFromBytes>::#method(bytes[i..i+size].try_into().unwrap())?;
+                    let val = <#ty as ::packbytes::TryFromBytes>::#method(#from_slice)?;
                     i += size;
                     val
                 }
@@ -417,8 +466,8 @@ fn tryfrombytes_struct_derive(item: ItemStruct) -> TokenStream {
         }
     })
     };
-    let from_le_fields = from_fields(quote!(try_from_le_bytes), quote!(from_le_bytes));
-    let from_be_fields = from_fields(quote!(try_from_be_bytes), quote!(from_be_bytes));
+    let from_le_fields = from_fields(quote!(try_from_le_bytes), quote!(from_le_bytes), quote!(decode_le_array));
+    let from_be_fields = from_fields(quote!(try_from_be_bytes), quote!(from_be_bytes), quote!(decode_be_array));
 
     let tokens = quote! {
         impl #generics ::packbytes::TryFromBytes for #name #generics {
@@ -505,6 +554,821 @@ fn tryfrombytes_enum_derive(item: ItemEnum) -> TokenStream {
     TokenStream::from(tokens)
 }
 
+/// Derive the `TryToBytes` trait for structs where each field implements it.
+///
+/// Every type implementing `ToBytes` implements `TryToBytes` that never fails. Deriving
+/// this trait instead of `ToBytes` is useful for structs with scaled fields, so that values
+/// that don't fit are reported instead of saturated.
+/// Like with `TryFromBytes`, array fields are supported only for the types implementing `ToBytes`.
+///
+/// # Endianness
+/// By default, the `FromBytes` and `ToBytes` derive macros assume that the data is prefered to be stored
+/// in the little endian order.
+/// You can change this by setting the attribute `#[packbytes(be)]` for big endian or `#[packbytes(ne)]`
+/// for the platform native endian.
+///
+/// # Scaled fields
+/// A floating point field can be stored in bytes as a scaled and biased integer by setting
+/// the attribute `#[packbytes(repr = i16, scale = 0.01, offset = -40.0)]` on it. The value of
+/// the field is then `raw * scale + offset`, where `raw` is the integer of the type `repr`.
+/// The scale defaults to `1.0` and the offset to `0.0`.
+///
+/// When encoding, the value is rounded to the nearest integer. If it does not fit into `repr`,
+/// `packbytes::error::OutOfRange` is returned, converted to the error type.
+///
+/// # Errors
+/// By default, the error type is `packbytes::errors::InvalidData`. You can provide a custom error
+/// type with the `packbytes_error` attribute. Like with `TryFromBytes`, it needs to implement
+/// `From<<T as TryToBytes>::Error>` for the type `T` of every field, and `From<OutOfRange>`
+/// when there are scaled fields.
+///
+/// ```
+/// # use packbytes_derive::{FromBytes, TryToBytes};
+/// #[derive(FromBytes, TryToBytes)]
+/// struct Measurement {
+///     #[packbytes(repr = i16, scale = 0.01, offset = -40.0)]
+///     temperature: f32,
+///     #[packbytes(repr = u8, scale = 0.5)]
+///     humidity: f32,
+/// }
+/// ```
+#[proc_macro_derive(TryToBytes, attributes(packbytes, packbytes_error))]
+pub fn trytobytes_derive(input: TokenStream) -> TokenStream {
+    let item: ItemStruct =
+        syn::parse(input).expect("#[derive(TryToBytes)] can be only applied to structs");
+
+    let name = &item.ident;
+    let generics = &item.generics;
+    let unit_fields = UnitFields::new();
+
+    let mut error = quote!(::packbytes::error::InvalidData);
+    let mut prefers_le = quote!(true);
+    for attr in item.attrs.iter() {
+        if let Meta::List(ref list) = attr.meta {
+            if list.path.is_ident("packbytes") {
+                get_endianness(&list.tokens, &mut prefers_le);
+            } else if list.path.is_ident("packbytes_error") {
+                error = list.tokens.clone();
+            }
+        }
+    }
+
+    let fields = match item.fields {
+        Fields::Named(fields) => fields.named.into_iter(),
+        Fields::Unnamed(fields) => fields.unnamed.into_iter(),
+        _ => unit_fields.into_iter(),
+    };
+
+    let fields = fields.enumerate().map(|(i, field)| {
+        let name = field.ident.map_or_else(
+            || {
+                let i = i.to_string();
+                let lit = LitInt::new(&i, Span::call_site());
+                quote!(#lit)
+            },
+            |n| quote!(#n),
+        );
+        (name, field.ty, get_scaled(&field.attrs))
+    });
+
+    let field_sizes = fields.clone().map(|(_, ty, scaled)| {
+        if let Some(Scaled { repr, .. }) = scaled {
+            quote! { <<#repr as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
+        } else if let Type::Array(arr) = ty {
+            let len = arr.len;
+            let aty = arr.elem;
+            quote! { (#len) * <<#aty as ::packbytes::ToBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
+        } else {
+            quote! { <<#ty as ::packbytes::TryToBytes>::Bytes as ::packbytes::ByteArray>::SIZE }
+        }
+    });
+
+    let to_fields = |method, regular_method, slice_method| {
+        fields.clone().map(move |(name, ty, scaled)| {
+        if let Some(scaled) = scaled {
+            let repr = &scaled.repr;
+            let rounded = scaled.encode_rounded(&name);
+            quote! {
+                let size = <<#repr as ::packbytes::ToBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
+                let rounded = { #rounded };
+                if !(rounded > <#repr>::MIN as f64 - 1.0 && rounded < <#repr>::MAX as f64 + 1.0) {
+                    return Err(::core::convert::From::from(::packbytes::error::OutOfRange));
+                }
+                bytes[i..i+size].copy_from_slice(&<#repr as ::packbytes::ToBytes>::#regular_method(rounded as #repr));
+                i += size;
+            }
+        } else if let Type::Array(arr) = ty {
+            let len = arr.len;
+            let aty = arr.elem;
+            quote! {
+                let size = <<#aty as ::packbytes::ToBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
+                <#aty as ::packbytes::ToBytes>::#slice_method(&self.#name, &mut bytes[i..i+(#len)*size])
+                    .expect("field slice has the exact size");
+                i += (#len)*size;
+            }
+        } else {
+            quote! {
+                let size = <<#ty as ::packbytes::TryToBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
+                bytes[i..i+size].copy_from_slice(::core::convert::AsRef::<[u8]>::as_ref(
+                    &<#ty as ::packbytes::TryToBytes>::#method(self.#name)?
+                ));
+                i += size;
+            }
+        }
+    })
+    };
+    let to_le_fields = to_fields(quote!(try_to_le_bytes), quote!(to_le_bytes), quote!(encode_le_slice));
+    let to_be_fields = to_fields(quote!(try_to_be_bytes), quote!(to_be_bytes), quote!(encode_be_slice));
+
+    let tokens = quote! {
+        impl #generics ::packbytes::TryToBytes for #name #generics {
+            type Bytes = [u8; #( #field_sizes + )* 0];
+            type Error = #error;
+
+            const PREFERS_LE: bool = #prefers_le;
+
+            #[inline]
+            fn try_to_le_bytes(self) -> Result<Self::Bytes, Self::Error> {
+                let mut bytes = <Self::Bytes as ::packbytes::ByteArray>::zeroed();
+                let mut i = 0;
+                #( #to_le_fields )*
+                Ok(bytes)
+            }
+
+            #[inline]
+            fn try_to_be_bytes(self) -> Result<Self::Bytes, Self::Error> {
+                let mut bytes = <Self::Bytes as ::packbytes::ByteArray>::zeroed();
+                let mut i = 0;
+                #( #to_be_fields )*
+                Ok(bytes)
+            }
+        }
+    };
+    TokenStream::from(tokens)
+}
+
+/// Derive a set of bit flags, packed as an integer.
+///
+/// # Enums
+/// For a fieldless enum, whose variants are the flags and their values are the positions of
+/// the bits, a new type holding a set of the flags is generated, with the name given by the
+/// `packbytes_flags` attribute (by default, the name of the enum followed by `Set`).
+/// Its width is set by the `repr` attribute on the enum.
+///
+/// The set supports the usual set operations, both with other sets and with single flags.
+/// It implements `FromBytes`, keeping the bits that don't correspond to any flag, so that they
+/// are written back unchanged.
+///
+/// ```
+/// # use packbytes_derive::PackedFlags;
+/// #[derive(PackedFlags)]
+/// #[repr(u16)]
+/// #[packbytes_flags(FileFlags)]
+/// enum FileFlag {
+///     Compressed = 0,
+///     Encrypted = 3,
+/// }
+///
+/// let flags = FileFlag::Compressed | FileFlag::Encrypted;
+/// assert_eq!(flags.bits(), 0b1001);
+/// assert!(flags.contains(FileFlag::Encrypted));
+/// assert!(!(flags - FileFlag::Encrypted).contains(FileFlag::Encrypted));
+/// ```
+///
+/// # Structs
+/// For a struct of `bool` fields, every field is a flag, stored at the bit given by its
+/// `#[packbytes(bit = 3)]` attribute, or by default at the bit given by its position in the struct.
+/// The struct itself supports the set operations. Its width is set by the `packbytes_repr`
+/// attribute on the struct. As it has nowhere to keep the bits that don't correspond to any flag,
+/// it is always in the strict mode: it implements `TryFromBytes`, returning an error if any such
+/// bit is set, so that unpacking and packing it never loses bits.
+///
+/// # Endianness
+/// By default, the flags are assumed to be stored in the little endian order.
+/// You can change this by setting the attribute `#[packbytes(be)]` for big endian or `#[packbytes(ne)]`
+/// for the platform native endian.
+///
+/// # Strict mode
+/// With the `packbytes_strict` attribute on an enum, `TryFromBytes` is implemented instead of `FromBytes`,
+/// returning an error if any bit that doesn't correspond to a flag is set.
+/// Like for enums deriving `TryFromBytes`, the error type is `packbytes::error::InvalidData` by default and
+/// can be set by the `packbytes_error` and `packbytes_error_exp` attributes.
+#[proc_macro_derive(
+    PackedFlags,
+    attributes(
+        packbytes,
+        packbytes_flags,
+        packbytes_repr,
+        packbytes_strict,
+        packbytes_error,
+        packbytes_error_exp
+    )
+)]
+pub fn packedflags_derive(input: TokenStream) -> TokenStream {
+    match syn::parse::<Item>(input) {
+        Ok(Item::Struct(item)) => packedflags_struct_derive(item),
+        Ok(Item::Enum(item)) => packedflags_enum_derive(item),
+        _ => panic!("#[derive(PackedFlags)] can be only applied to structs or enums"),
+    }
+}
+
+fn packedflags_enum_derive(item: ItemEnum) -> TokenStream {
+    for variant in item.variants.iter() {
+        let Fields::Unit = variant.fields else {
+            panic!("#[derive(PackedFlags)] can be only applied to fieldless enums");
+        };
+    }
+
+    let name = &item.ident;
+    let vis = &item.vis;
+    let mut set = format_ident!("{}Set", name);
+    let mut repr = quote!(u8);
+    let mut prefers_le = quote!(true);
+    let mut strict = None;
+    let mut error = quote!(::packbytes::error::InvalidData);
+    let mut error_exp = quote!(Default::default());
+    for attr in item.attrs.iter() {
+        match attr.meta {
+            Meta::List(ref list) if list.path.is_ident("packbytes") => {
+                get_endianness(&list.tokens, &mut prefers_le);
+            }
+            Meta::List(ref list) if list.path.is_ident("repr") => {
+                get_numeric_type(&list.tokens, &mut repr);
+            }
+            Meta::List(ref list) if list.path.is_ident("packbytes_flags") => {
+                set = syn::parse2(list.tokens.clone())
+                    .expect("the `packbytes_flags` attribute should contain the name of the set type");
+            }
+            Meta::List(ref list) if list.path.is_ident("packbytes_error") => {
+                error = list.tokens.clone();
+            }
+            Meta::List(ref list) if list.path.is_ident("packbytes_error_exp") => {
+                error_exp = list.tokens.clone();
+            }
+            Meta::Path(ref path) if path.is_ident("packbytes_strict") => {
+                strict = Some(());
+            }
+            _ => {}
+        }
+    }
+    let strict = strict.map(|()| (error, error_exp));
+
+    let variants: Vec<_> = item.variants.iter().map(|v| &v.ident).collect();
+    let masks: Vec<_> = variants
+        .iter()
+        .map(|v| quote!(((1 as #repr) << (#name::#v as u32))))
+        .collect();
+    let names = variants.iter().map(|v| v.to_string());
+
+    let set_doc = format!("A set of [`{}`] flags.", name);
+    let bytes_impls = flags_bytes_impls(
+        &quote!(#set),
+        &repr,
+        &prefers_le,
+        strict,
+        quote!(Self::from_bits(bits)),
+        quote!(Self::from_bits_retain(bits)),
+        quote!(self.0),
+    );
+
+    let tokens = quote! {
+        #[doc = #set_doc]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        #vis struct #set(#repr);
+
+        impl #set {
+            /// The empty set.
+            pub const EMPTY: Self = #set(0);
+            /// The set of all the flags.
+            pub const ALL: Self = #set(0 #( | #masks )*);
+
+            /// Create a set from its bits, returning `None` if a bit not corresponding to any flag is set.
+            #[inline]
+            pub const fn from_bits(bits: #repr) -> Option<Self> {
+                if bits & !Self::ALL.0 == 0 {
+                    Some(#set(bits))
+                } else {
+                    None
+                }
+            }
+
+            /// Create a set from its bits, dropping the bits not corresponding to any flag.
+            #[inline]
+            pub const fn from_bits_truncate(bits: #repr) -> Self {
+                #set(bits & Self::ALL.0)
+            }
+
+            /// Create a set from its bits, keeping the bits not corresponding to any flag.
+            #[inline]
+            pub const fn from_bits_retain(bits: #repr) -> Self {
+                #set(bits)
+            }
+
+            /// Return the bits of the set.
+            #[inline]
+            pub const fn bits(self) -> #repr {
+                self.0
+            }
+
+            /// Is the set empty?
+            #[inline]
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Does the set contain all the flags?
+            #[inline]
+            pub const fn is_all(self) -> bool {
+                self.0 & Self::ALL.0 == Self::ALL.0
+            }
+
+            /// Does the set contain all the given flags?
+            #[inline]
+            pub fn contains(self, other: impl Into<Self>) -> bool {
+                let other = other.into().0;
+                self.0 & other == other
+            }
+
+            /// Does the set contain any of the given flags?
+            #[inline]
+            pub fn intersects(self, other: impl Into<Self>) -> bool {
+                self.0 & other.into().0 != 0
+            }
+
+            /// Add the given flags to the set.
+            #[inline]
+            pub fn insert(&mut self, other: impl Into<Self>) {
+                self.0 |= other.into().0;
+            }
+
+            /// Remove the given flags from the set.
+            #[inline]
+            pub fn remove(&mut self, other: impl Into<Self>) {
+                self.0 &= !other.into().0;
+            }
+
+            /// Toggle the given flags in the set.
+            #[inline]
+            pub fn toggle(&mut self, other: impl Into<Self>) {
+                self.0 ^= other.into().0;
+            }
+
+            /// Add or remove the given flags, depending on `value`.
+            #[inline]
+            pub fn set(&mut self, other: impl Into<Self>, value: bool) {
+                if value {
+                    self.insert(other);
+                } else {
+                    self.remove(other);
+                }
+            }
+
+            /// Return an iterator over the flags in the set.
+            pub fn iter(self) -> impl Iterator<Item = #name> {
+                [#( (#masks, #name::#variants) ),*]
+                    .into_iter()
+                    .filter_map(move |(mask, flag)| (self.0 & mask != 0).then_some(flag))
+            }
+        }
+
+        impl From<#name> for #set {
+            #[inline]
+            fn from(flag: #name) -> Self {
+                #set((1 as #repr) << (flag as u32))
+            }
+        }
+
+        impl<T: Into<#set>> ::core::iter::FromIterator<T> for #set {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let mut set = Self::EMPTY;
+                for flag in iter {
+                    set.insert(flag);
+                }
+                set
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::BitOr<T> for #name {
+            type Output = #set;
+
+            #[inline]
+            fn bitor(self, rhs: T) -> #set {
+                #set::from(self) | rhs
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::BitOr<T> for #set {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: T) -> Self {
+                #set(self.0 | rhs.into().0)
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::BitAnd<T> for #set {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, rhs: T) -> Self {
+                #set(self.0 & rhs.into().0)
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::BitXor<T> for #set {
+            type Output = Self;
+
+            #[inline]
+            fn bitxor(self, rhs: T) -> Self {
+                #set(self.0 ^ rhs.into().0)
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::Sub<T> for #set {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: T) -> Self {
+                #set(self.0 & !rhs.into().0)
+            }
+        }
+
+        /// The complement of the set, containing only the known flags.
+        impl ::core::ops::Not for #set {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                #set(!self.0 & Self::ALL.0)
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::BitOrAssign<T> for #set {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: T) {
+                self.0 |= rhs.into().0;
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::BitAndAssign<T> for #set {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: T) {
+                self.0 &= rhs.into().0;
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::BitXorAssign<T> for #set {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: T) {
+                self.0 ^= rhs.into().0;
+            }
+        }
+
+        impl<T: Into<#set>> ::core::ops::SubAssign<T> for #set {
+            #[inline]
+            fn sub_assign(&mut self, rhs: T) {
+                self.0 &= !rhs.into().0;
+            }
+        }
+
+        impl ::core::fmt::Debug for #set {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(concat!(stringify!(#set), "("))?;
+                let mut first = true;
+                for (mask, name) in [#( (#masks, #names) ),*] {
+                    if self.0 & mask != 0 {
+                        if !first {
+                            f.write_str(" | ")?;
+                        }
+                        f.write_str(name)?;
+                        first = false;
+                    }
+                }
+                let unknown = self.0 & !Self::ALL.0;
+                if unknown != 0 {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{:#x}", unknown)?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        #bytes_impls
+    };
+    TokenStream::from(tokens)
+}
+
+fn packedflags_struct_derive(item: ItemStruct) -> TokenStream {
+    let name = &item.ident;
+    let unit_fields = UnitFields::new();
+
+    let mut repr = quote!(u8);
+    let mut prefers_le = quote!(true);
+    let mut error = quote!(::packbytes::error::InvalidData);
+    let mut error_exp = quote!(Default::default());
+    for attr in item.attrs.iter() {
+        match attr.meta {
+            Meta::List(ref list) if list.path.is_ident("packbytes") => {
+                get_endianness(&list.tokens, &mut prefers_le);
+            }
+            Meta::List(ref list) if list.path.is_ident("packbytes_repr") => {
+                get_numeric_type(&list.tokens, &mut repr);
+            }
+            Meta::List(ref list) if list.path.is_ident("packbytes_error") => {
+                error = list.tokens.clone();
+            }
+            Meta::List(ref list) if list.path.is_ident("packbytes_error_exp") => {
+                error_exp = list.tokens.clone();
+            }
+            _ => {}
+        }
+    }
+    // The struct has nowhere to keep the bits not corresponding to any flag, so it is always
+    // strict, lest they be lost when it is written back.
+    let strict = Some((error, error_exp));
+
+    let fields = match item.fields {
+        Fields::Named(fields) => fields.named.into_iter(),
+        Fields::Unnamed(fields) => fields.unnamed.into_iter(),
+        _ => unit_fields.into_iter(),
+    };
+
+    let fields: Vec<_> = fields
+        .enumerate()
+        .map(|(i, field)| {
+            let bit = get_bit(&field.attrs).unwrap_or_else(|| {
+                let lit = LitInt::new(&i.to_string(), Span::call_site());
+                syn::parse_quote!(#lit)
+            });
+            let name = field.ident.map_or_else(
+                || {
+                    let i = i.to_string();
+                    let lit = LitInt::new(&i, Span::call_site());
+                    quote!(#lit)
+                },
+                |n| quote!(#n),
+            );
+            (name, quote!(((1 as #repr) << (#bit))))
+        })
+        .collect();
+    let names: Vec<_> = fields.iter().map(|(name, _)| name).collect();
+    let masks: Vec<_> = fields.iter().map(|(_, mask)| mask).collect();
+
+    let bytes_impls = flags_bytes_impls(
+        &quote!(#name),
+        &repr,
+        &prefers_le,
+        strict,
+        quote!(Self::from_bits(bits)),
+        quote!(Self::from_bits_truncate(bits)),
+        quote!(self.bits()),
+    );
+
+    let binary_ops = [
+        (quote!(BitOr), quote!(bitor), quote!(|), quote!(BitOrAssign), quote!(bitor_assign), quote!(|=)),
+        (quote!(BitAnd), quote!(bitand), quote!(&), quote!(BitAndAssign), quote!(bitand_assign), quote!(&=)),
+        (quote!(BitXor), quote!(bitxor), quote!(^), quote!(BitXorAssign), quote!(bitxor_assign), quote!(^=)),
+        (quote!(Sub), quote!(sub), quote!(& !), quote!(SubAssign), quote!(sub_assign), quote!(&= !)),
+    ]
+    .into_iter()
+    .map(|(op, method, token, op_assign, method_assign, token_assign)| {
+        quote! {
+            impl ::core::ops::#op for #name {
+                type Output = Self;
+
+                #[inline]
+                fn #method(self, rhs: Self) -> Self {
+                    Self { #( #names: self.#names #token rhs.#names, )* }
+                }
+            }
+
+            impl ::core::ops::#op_assign for #name {
+                #[inline]
+                fn #method_assign(&mut self, rhs: Self) {
+                    #( self.#names #token_assign rhs.#names; )*
+                }
+            }
+        }
+    });
+
+    let tokens = quote! {
+        impl #name {
+            /// The bits corresponding to the flags.
+            const ALL_BITS: #repr = 0 #( | #masks )*;
+
+            /// Create the flags from their bits, returning `None` if a bit not corresponding to any flag is set.
+            #[inline]
+            pub const fn from_bits(bits: #repr) -> Option<Self> {
+                if bits & !Self::ALL_BITS == 0 {
+                    Some(Self::from_bits_truncate(bits))
+                } else {
+                    None
+                }
+            }
+
+            /// Create the flags from their bits, dropping the bits not corresponding to any flag.
+            #[inline]
+            pub const fn from_bits_truncate(bits: #repr) -> Self {
+                Self { #( #names: bits & #masks != 0, )* }
+            }
+
+            /// Return the bits of the flags.
+            #[inline]
+            pub const fn bits(&self) -> #repr {
+                0 #( | if self.#names { #masks } else { 0 } )*
+            }
+        }
+
+        #( #binary_ops )*
+
+        impl ::core::ops::Not for #name {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                Self { #( #names: !self.#names, )* }
+            }
+        }
+
+        #bytes_impls
+    };
+    TokenStream::from(tokens)
+}
+
+/// Implementations of `FromBytes` (or `TryFromBytes` in the strict mode) and `ToBytes` for flags
+/// stored in the integer `repr`. The expressions converting from `bits` return an option
+/// (`from_strict`) and a value (`from_lenient`), `to_bits` converts from `self`.
+fn flags_bytes_impls(
+    ty: &proc_macro2::TokenStream,
+    repr: &proc_macro2::TokenStream,
+    prefers_le: &proc_macro2::TokenStream,
+    strict: Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)>,
+    from_strict: proc_macro2::TokenStream,
+    from_lenient: proc_macro2::TokenStream,
+    to_bits: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let from_impl = if let Some((error, error_exp)) = strict {
+        quote! {
+            impl ::packbytes::TryFromBytes for #ty {
+                type Bytes = [u8; #repr::BITS as usize / 8];
+                type Error = #error;
+
+                const PREFERS_LE: bool = #prefers_le;
+
+                #[inline]
+                fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, Self::Error> {
+                    let bits = #repr::from_le_bytes(bytes);
+                    #from_strict.ok_or_else(|| #error_exp)
+                }
+
+                #[inline]
+                fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, Self::Error> {
+                    let bits = #repr::from_be_bytes(bytes);
+                    #from_strict.ok_or_else(|| #error_exp)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl ::packbytes::FromBytes for #ty {
+                type Bytes = [u8; #repr::BITS as usize / 8];
+
+                const PREFERS_LE: bool = #prefers_le;
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    let bits = #repr::from_le_bytes(bytes);
+                    #from_lenient
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    let bits = #repr::from_be_bytes(bytes);
+                    #from_lenient
+                }
+            }
+        }
+    };
+
+    quote! {
+        #from_impl
+
+        impl ::packbytes::ToBytes for #ty {
+            type Bytes = [u8; #repr::BITS as usize / 8];
+
+            const PREFERS_LE: bool = #prefers_le;
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                (#to_bits).to_le_bytes()
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                (#to_bits).to_be_bytes()
+            }
+        }
+    }
+}
+
+/// Create a byte array of the type `bytes_ty` by copying bytes from the slice expression `slice`.
+fn bytes_from_slice(
+    bytes_ty: proc_macro2::TokenStream,
+    slice: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {{
+        let mut field_bytes = <#bytes_ty as ::packbytes::ByteArray>::zeroed();
+        ::core::convert::AsMut::<[u8]>::as_mut(&mut field_bytes).copy_from_slice(&#slice);
+        field_bytes
+    }}
+}
+
+/// A numeric field stored in bytes as a scaled and biased integer, set by the field attribute
+/// `#[packbytes(repr = ..., scale = ..., offset = ...)]`.
+struct Scaled {
+    repr: Type,
+    scale: Expr,
+    offset: Expr,
+}
+
+impl Scaled {
+    /// An expression reading the integer from `bytes` and converting it to the field type `ty`.
+    fn decode(&self, ty: &Type, method: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Scaled {
+            repr,
+            scale,
+            offset,
+        } = self;
+        let from_slice = bytes_from_slice(
+            quote!(<#repr as ::packbytes::FromBytes>::Bytes),
+            quote!(bytes[i..i+size]),
+        );
+        quote! {{
+            let size = <<#repr as ::packbytes::FromBytes>::Bytes as ::packbytes::ByteArray>::SIZE;
+            let raw = <#repr as ::packbytes::FromBytes>::#method(#from_slice);
+            i += size;
+            (raw as f64 * (#scale) as f64 + (#offset) as f64) as #ty
+        }}
+    }
+
+    /// Statements computing the value of the field `name` in the units of the integer, moved by
+    /// a half away from zero, so that casting it to the integer rounds it.
+    fn encode_rounded(&self, name: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Scaled { scale, offset, .. } = self;
+        quote! {
+            let scaled = (self.#name as f64 - (#offset) as f64) / (#scale) as f64;
+            if scaled >= 0.0 { scaled + 0.5 } else { scaled - 0.5 }
+        }
+    }
+}
+
+fn get_scaled(attrs: &[Attribute]) -> Option<Scaled> {
+    let list = attrs.iter().find_map(|attr| match attr.meta {
+        Meta::List(ref list) if list.path.is_ident("packbytes") => Some(list),
+        _ => None,
+    })?;
+    let args = list
+        .parse_args_with(Punctuated::<MetaNameValue, Comma>::parse_terminated)
+        .expect("the `packbytes` attribute of a field should be of the form `repr = i16, scale = 0.01, offset = -40.0`");
+
+    let mut repr = None;
+    let mut scale = syn::parse_quote!(1.0);
+    let mut offset = syn::parse_quote!(0.0);
+    for arg in args {
+        if arg.path.is_ident("repr") {
+            repr = Some(syn::parse2::<Type>(arg.value.to_token_stream()).unwrap());
+        } else if arg.path.is_ident("scale") {
+            scale = arg.value;
+        } else if arg.path.is_ident("offset") {
+            offset = arg.value;
+        } else {
+            panic!("the valid keys of the `packbytes` attribute of a field are \"repr\", \"scale\" and \"offset\"");
+        }
+    }
+    let repr = repr.expect("the `packbytes` attribute of a field needs to set `repr`");
+    Some(Scaled {
+        repr,
+        scale,
+        offset,
+    })
+}
+
+fn get_bit(attrs: &[Attribute]) -> Option<Expr> {
+    let list = attrs.iter().find_map(|attr| match attr.meta {
+        Meta::List(ref list) if list.path.is_ident("packbytes") => Some(list),
+        _ => None,
+    })?;
+    let arg = list
+        .parse_args::<MetaNameValue>()
+        .expect("the `packbytes` attribute of a flag should be of the form `bit = 3`");
+    if !arg.path.is_ident("bit") {
+        panic!("the valid key of the `packbytes` attribute of a flag is \"bit\"");
+    }
+    Some(arg.value)
+}
+
 fn get_endianness(ts: &proc_macro2::TokenStream, end: &mut proc_macro2::TokenStream) {
     let ident = syn::parse2::<Ident>(ts.clone()).unwrap().to_string();
     match ident.as_str() {
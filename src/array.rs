@@ -0,0 +1,272 @@
+use crate::{private, ByteArray, FromBytes, ToBytes, TryFromBytes, TryToBytes};
+use core::mem::{align_of, size_of};
+use core::ops;
+
+/// A byte array made of `N` byte arrays of the type `B`, stored one after another.
+///
+/// Stable Rust cannot use `N * B::SIZE` as a length of an array, so this type is used as the
+/// `Bytes` of [`PackedArray`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ArrayBytes<B, const N: usize>(pub [B; N]);
+
+impl<B: ByteArray, const N: usize> private::ByteArray for ArrayBytes<B, N> {}
+
+impl<B: ByteArray, const N: usize> ByteArray for ArrayBytes<B, N> {
+    const SIZE: usize = N * B::SIZE;
+
+    fn zeroed() -> Self {
+        ArrayBytes(core::array::from_fn(|_| B::zeroed()))
+    }
+}
+
+impl<B: ByteArray, const N: usize> AsRef<[u8]> for ArrayBytes<B, N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        const { assert!(size_of::<Self>() == Self::SIZE && align_of::<Self>() == 1) };
+        // SAFETY: `ByteArray` is sealed and every type implementing it consists only of `SIZE`
+        // bytes with the alignment of 1, without any padding. The same then holds for an array
+        // of them, which `Self` is a transparent wrapper of, as asserted above.
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), Self::SIZE) }
+    }
+}
+
+impl<B: ByteArray, const N: usize> AsMut<[u8]> for ArrayBytes<B, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        const { assert!(size_of::<Self>() == Self::SIZE && align_of::<Self>() == 1) };
+        // SAFETY: see `as_ref`. Every sequence of bytes is a valid value of a byte array.
+        unsafe { core::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast(), Self::SIZE) }
+    }
+}
+
+impl<B: ByteArray, const N: usize> ops::Index<usize> for ArrayBytes<B, N> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        &self.as_ref()[index]
+    }
+}
+
+impl<B: ByteArray, const N: usize> ops::IndexMut<usize> for ArrayBytes<B, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl<B: ByteArray, const N: usize> ops::Index<ops::Range<usize>> for ArrayBytes<B, N> {
+    type Output = [u8];
+
+    #[inline]
+    fn index(&self, index: ops::Range<usize>) -> &[u8] {
+        &self.as_ref()[index]
+    }
+}
+
+impl<B: ByteArray, const N: usize> ops::IndexMut<ops::Range<usize>> for ArrayBytes<B, N> {
+    #[inline]
+    fn index_mut(&mut self, index: ops::Range<usize>) -> &mut [u8] {
+        &mut self.as_mut()[index]
+    }
+}
+
+/// An array of `N` values of the type `T`, packed one after another.
+///
+/// Const generics in stable don't allow implementing `FromBytes` and `ToBytes` for arrays `[T; N]`
+/// where `T` implements them. This wrapper does, so arrays of any packable values can be used
+/// on their own, e.g. with `read_packed`. It dereferences to the inner array.
+///
+/// The preferred byte order is the one of the element type.
+///
+/// Like for array fields in the derive macros, `TryFromBytes` is provided only for `T: FromBytes`.
+/// Arrays of values that can fail to be unpacked, such as `bool`, use [`TryPackedArray`] instead.
+///
+/// ```
+/// use packbytes::{FromBytes, PackedArray, ToBytes};
+///
+/// let arr = PackedArray([0x1234u16, 0x5678]);
+/// assert_eq!(arr.to_le_bytes().as_ref(), [0x34, 0x12, 0x78, 0x56]);
+/// assert_eq!(PackedArray::from_le_bytes(arr.to_le_bytes()), arr);
+/// assert_eq!(arr[1], 0x5678);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedArray<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> PackedArray<T, N> {
+    /// Return the inner array.
+    #[inline]
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> ops::Deref for PackedArray<T, N> {
+    type Target = [T; N];
+
+    #[inline]
+    fn deref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> ops::DerefMut for PackedArray<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for PackedArray<T, N> {
+    #[inline]
+    fn from(arr: [T; N]) -> Self {
+        PackedArray(arr)
+    }
+}
+
+impl<T, const N: usize> From<PackedArray<T, N>> for [T; N] {
+    #[inline]
+    fn from(arr: PackedArray<T, N>) -> Self {
+        arr.0
+    }
+}
+
+impl<T: FromBytes, const N: usize> FromBytes for PackedArray<T, N> {
+    type Bytes = ArrayBytes<T::Bytes, N>;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        PackedArray(T::decode_le_array(bytes.as_ref()))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        PackedArray(T::decode_be_array(bytes.as_ref()))
+    }
+}
+
+impl<T: ToBytes, const N: usize> ToBytes for PackedArray<T, N> {
+    type Bytes = ArrayBytes<T::Bytes, N>;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        ArrayBytes(self.0.map(T::to_le_bytes))
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        ArrayBytes(self.0.map(T::to_be_bytes))
+    }
+}
+
+/// An array of `N` values of the type `T`, packed one after another, which can fail to be
+/// unpacked or packed.
+///
+/// This is the fallible counterpart of [`PackedArray`]: it implements `TryFromBytes` and
+/// `TryToBytes` when `T` does, with the error of the first element that fails. It dereferences
+/// to the inner array.
+///
+/// ```
+/// use packbytes::error::InvalidBool;
+/// use packbytes::{ArrayBytes, TryFromBytes, TryPackedArray};
+///
+/// let arr = TryPackedArray::<bool, 2>::try_from_le_bytes(ArrayBytes([[1], [0]]));
+/// assert_eq!(arr, Ok(TryPackedArray([true, false])));
+/// let arr = TryPackedArray::<bool, 2>::try_from_le_bytes(ArrayBytes([[1], [2]]));
+/// assert_eq!(arr, Err(InvalidBool));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TryPackedArray<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> TryPackedArray<T, N> {
+    /// Return the inner array.
+    #[inline]
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> ops::Deref for TryPackedArray<T, N> {
+    type Target = [T; N];
+
+    #[inline]
+    fn deref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> ops::DerefMut for TryPackedArray<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for TryPackedArray<T, N> {
+    #[inline]
+    fn from(arr: [T; N]) -> Self {
+        TryPackedArray(arr)
+    }
+}
+
+impl<T, const N: usize> From<TryPackedArray<T, N>> for [T; N] {
+    #[inline]
+    fn from(arr: TryPackedArray<T, N>) -> Self {
+        arr.0
+    }
+}
+
+/// Convert each element of an array by a fallible function, stopping at the first error.
+fn try_map<B, T, E, const N: usize>(
+    arr: [B; N],
+    f: impl Fn(B) -> Result<T, E>,
+) -> Result<[T; N], E> {
+    let mut error = None;
+    let values = arr.map(|b| match error {
+        Some(_) => None,
+        None => f(b).map_err(|e| error = Some(e)).ok(),
+    });
+    match error {
+        Some(e) => Err(e),
+        None => Ok(values.map(|v| v.expect("every element was converted"))),
+    }
+}
+
+impl<T: TryFromBytes, const N: usize> TryFromBytes for TryPackedArray<T, N> {
+    type Bytes = ArrayBytes<T::Bytes, N>;
+    type Error = T::Error;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, Self::Error> {
+        try_map(bytes.0, T::try_from_le_bytes).map(TryPackedArray)
+    }
+
+    #[inline]
+    fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, Self::Error> {
+        try_map(bytes.0, T::try_from_be_bytes).map(TryPackedArray)
+    }
+}
+
+impl<T: TryToBytes, const N: usize> TryToBytes for TryPackedArray<T, N> {
+    type Bytes = ArrayBytes<T::Bytes, N>;
+    type Error = T::Error;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn try_to_le_bytes(self) -> Result<Self::Bytes, Self::Error> {
+        try_map(self.0, T::try_to_le_bytes).map(ArrayBytes)
+    }
+
+    #[inline]
+    fn try_to_be_bytes(self) -> Result<Self::Bytes, Self::Error> {
+        try_map(self.0, T::try_to_be_bytes).map(ArrayBytes)
+    }
+}
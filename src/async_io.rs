@@ -0,0 +1,293 @@
+/// Generate the extension traits for asynchronous readers and writers, given the functions
+/// `read_exact` and `write_all` for them.
+macro_rules! async_packed_ext {
+    ($read:path, $write:path) => {
+        use crate::io::invalid_data;
+        use crate::{ByteArray, FromBytes, ToBytes, TryFromBytes, TryToBytes};
+        use core::future::Future;
+        use std::error::Error;
+        use std::io;
+
+        /// Extension methods for reading packed values asynchronously, implemented for every
+        /// reader.
+        ///
+        /// Like the methods of `ReadPackedExt`, each method reads exactly the bytes of one value.
+        pub trait AsyncReadPackedExt: $read {
+            /// Read a value in its preferred byte order.
+            fn read_packed_async<T: FromBytes>(&mut self) -> impl Future<Output = io::Result<T>>;
+
+            /// Read a value in the little endian byte order.
+            fn read_packed_le_async<T: FromBytes>(&mut self)
+                -> impl Future<Output = io::Result<T>>;
+
+            /// Read a value in the big endian byte order.
+            fn read_packed_be_async<T: FromBytes>(&mut self)
+                -> impl Future<Output = io::Result<T>>;
+
+            /// Try to read a value in its preferred byte order.
+            ///
+            /// If the bytes don't represent a valid value, an error of the kind `InvalidData`
+            /// is returned, which wraps the error of the conversion.
+            fn try_read_packed_async<T>(&mut self) -> impl Future<Output = io::Result<T>>
+            where
+                T: TryFromBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>;
+
+            /// Try to read a value in the little endian byte order.
+            ///
+            /// If the bytes don't represent a valid value, an error of the kind `InvalidData`
+            /// is returned, which wraps the error of the conversion.
+            fn try_read_packed_le_async<T>(&mut self) -> impl Future<Output = io::Result<T>>
+            where
+                T: TryFromBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>;
+
+            /// Try to read a value in the big endian byte order.
+            ///
+            /// If the bytes don't represent a valid value, an error of the kind `InvalidData`
+            /// is returned, which wraps the error of the conversion.
+            fn try_read_packed_be_async<T>(&mut self) -> impl Future<Output = io::Result<T>>
+            where
+                T: TryFromBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>;
+        }
+
+        impl<R: $read + Unpin + ?Sized> AsyncReadPackedExt for R {
+            async fn read_packed_async<T: FromBytes>(&mut self) -> io::Result<T> {
+                read_bytes(self).await.map(T::from_bytes)
+            }
+
+            async fn read_packed_le_async<T: FromBytes>(&mut self) -> io::Result<T> {
+                read_bytes(self).await.map(T::from_le_bytes)
+            }
+
+            async fn read_packed_be_async<T: FromBytes>(&mut self) -> io::Result<T> {
+                read_bytes(self).await.map(T::from_be_bytes)
+            }
+
+            async fn try_read_packed_async<T>(&mut self) -> io::Result<T>
+            where
+                T: TryFromBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>,
+            {
+                T::try_from_bytes(read_bytes(self).await?).map_err(invalid_data)
+            }
+
+            async fn try_read_packed_le_async<T>(&mut self) -> io::Result<T>
+            where
+                T: TryFromBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>,
+            {
+                T::try_from_le_bytes(read_bytes(self).await?).map_err(invalid_data)
+            }
+
+            async fn try_read_packed_be_async<T>(&mut self) -> io::Result<T>
+            where
+                T: TryFromBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>,
+            {
+                T::try_from_be_bytes(read_bytes(self).await?).map_err(invalid_data)
+            }
+        }
+
+        /// Extension methods for writing packed values asynchronously, implemented for every
+        /// writer.
+        pub trait AsyncWritePackedExt: $write {
+            /// Write a value in its preferred byte order.
+            fn write_packed_async<T: ToBytes>(
+                &mut self,
+                value: T,
+            ) -> impl Future<Output = io::Result<()>>;
+
+            /// Write a value in the little endian byte order.
+            fn write_packed_le_async<T: ToBytes>(
+                &mut self,
+                value: T,
+            ) -> impl Future<Output = io::Result<()>>;
+
+            /// Write a value in the big endian byte order.
+            fn write_packed_be_async<T: ToBytes>(
+                &mut self,
+                value: T,
+            ) -> impl Future<Output = io::Result<()>>;
+
+            /// Try to write a value in its preferred byte order.
+            ///
+            /// If the value can't be packed, an error of the kind `InvalidData` is returned,
+            /// which wraps the error of the conversion.
+            fn try_write_packed_async<T>(
+                &mut self,
+                value: T,
+            ) -> impl Future<Output = io::Result<()>>
+            where
+                T: TryToBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>;
+
+            /// Try to write a value in the little endian byte order.
+            ///
+            /// If the value can't be packed, an error of the kind `InvalidData` is returned,
+            /// which wraps the error of the conversion.
+            fn try_write_packed_le_async<T>(
+                &mut self,
+                value: T,
+            ) -> impl Future<Output = io::Result<()>>
+            where
+                T: TryToBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>;
+
+            /// Try to write a value in the big endian byte order.
+            ///
+            /// If the value can't be packed, an error of the kind `InvalidData` is returned,
+            /// which wraps the error of the conversion.
+            fn try_write_packed_be_async<T>(
+                &mut self,
+                value: T,
+            ) -> impl Future<Output = io::Result<()>>
+            where
+                T: TryToBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>;
+        }
+
+        impl<W: $write + Unpin + ?Sized> AsyncWritePackedExt for W {
+            async fn write_packed_async<T: ToBytes>(&mut self, value: T) -> io::Result<()> {
+                write_all(self, value.to_bytes().as_ref()).await
+            }
+
+            async fn write_packed_le_async<T: ToBytes>(&mut self, value: T) -> io::Result<()> {
+                write_all(self, value.to_le_bytes().as_ref()).await
+            }
+
+            async fn write_packed_be_async<T: ToBytes>(&mut self, value: T) -> io::Result<()> {
+                write_all(self, value.to_be_bytes().as_ref()).await
+            }
+
+            async fn try_write_packed_async<T>(&mut self, value: T) -> io::Result<()>
+            where
+                T: TryToBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>,
+            {
+                let bytes = value.try_to_bytes().map_err(invalid_data)?;
+                write_all(self, bytes.as_ref()).await
+            }
+
+            async fn try_write_packed_le_async<T>(&mut self, value: T) -> io::Result<()>
+            where
+                T: TryToBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>,
+            {
+                let bytes = value.try_to_le_bytes().map_err(invalid_data)?;
+                write_all(self, bytes.as_ref()).await
+            }
+
+            async fn try_write_packed_be_async<T>(&mut self, value: T) -> io::Result<()>
+            where
+                T: TryToBytes,
+                T::Error: Into<Box<dyn Error + Send + Sync>>,
+            {
+                let bytes = value.try_to_be_bytes().map_err(invalid_data)?;
+                write_all(self, bytes.as_ref()).await
+            }
+        }
+
+        /// Read a byte array of the type `B` from a reader by a single `read_exact`.
+        async fn read_bytes<B: ByteArray, R: $read + Unpin + ?Sized>(
+            reader: &mut R,
+        ) -> io::Result<B> {
+            let mut bytes = B::zeroed();
+            read_exact(reader, bytes.as_mut()).await?;
+            Ok(bytes)
+        }
+    };
+}
+
+/// Reading and writing packed values asynchronously with the I/O traits of `tokio`.
+///
+/// ```
+/// # async fn run() -> std::io::Result<()> {
+/// use packbytes::ext::tokio::{AsyncReadPackedExt, AsyncWritePackedExt};
+///
+/// let mut writer = Vec::new();
+/// writer.write_packed_async(1u16).await?;
+/// writer.write_packed_be_async(2u16).await?;
+///
+/// let mut reader = &writer[..];
+/// assert_eq!(reader.read_packed_async::<u16>().await?, 1);
+/// assert_eq!(reader.read_packed_be_async::<u16>().await?, 2);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub mod tokio {
+    use ::tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+    async_packed_ext!(AsyncRead, AsyncWrite);
+
+    async fn read_exact<R: AsyncRead + Unpin + ?Sized>(
+        reader: &mut R,
+        buf: &mut [u8],
+    ) -> io::Result<()> {
+        reader.read_exact(buf).await.map(drop)
+    }
+
+    async fn write_all<W: AsyncWrite + Unpin + ?Sized>(
+        writer: &mut W,
+        buf: &[u8],
+    ) -> io::Result<()> {
+        writer.write_all(buf).await
+    }
+}
+
+/// Reading and writing packed values asynchronously with the I/O traits of `futures-io`.
+///
+/// ```
+/// # async fn run() -> std::io::Result<()> {
+/// use packbytes::ext::futures::{AsyncReadPackedExt, AsyncWritePackedExt};
+///
+/// let mut writer = Vec::new();
+/// writer.write_packed_async(1u16).await?;
+/// writer.write_packed_be_async(2u16).await?;
+///
+/// let mut reader = &writer[..];
+/// assert_eq!(reader.read_packed_async::<u16>().await?, 1);
+/// assert_eq!(reader.read_packed_be_async::<u16>().await?, 2);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "futures-io")]
+pub mod futures {
+    use core::future::poll_fn;
+    use core::pin::Pin;
+    use futures_io::{AsyncRead, AsyncWrite};
+
+    async_packed_ext!(AsyncRead, AsyncWrite);
+
+    async fn read_exact<R: AsyncRead + Unpin + ?Sized>(
+        reader: &mut R,
+        mut buf: &mut [u8],
+    ) -> io::Result<()> {
+        while !buf.is_empty() {
+            match poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, buf)).await {
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => buf = &mut buf[n..],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    async fn write_all<W: AsyncWrite + Unpin + ?Sized>(
+        writer: &mut W,
+        mut buf: &[u8],
+    ) -> io::Result<()> {
+        while !buf.is_empty() {
+            match poll_fn(|cx| Pin::new(&mut *writer).poll_write(cx, buf)).await {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => buf = &buf[n..],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
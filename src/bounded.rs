@@ -0,0 +1,109 @@
+use crate::error::OutOfBounds;
+use crate::{ToBytes, TryFromBytes};
+use core::fmt;
+
+/// An integer of the type `T` that is guaranteed to lie in the range `MIN..=MAX`.
+///
+/// It is packed in the same way as the inner integer, but reading a value out of the range
+/// fails with [`OutOfBounds`]. It is implemented for the fixed-size primitive integers up to `i128`
+/// (the type of the bounds), that is, all of them except `u128`. Creating a value with `MIN`
+/// greater than `MAX` fails to compile.
+///
+/// ```
+/// use packbytes::{Bounded, ToBytes, TryFromBytes};
+///
+/// type Percentage = Bounded<u8, 0, 100>;
+///
+/// assert_eq!(Percentage::try_from_bytes([42]).map(Percentage::get), Ok(42));
+/// assert!(Percentage::try_from_bytes([101]).is_err());
+/// assert_eq!(Percentage::new(100).unwrap().to_bytes(), [100]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128>(T);
+
+impl<T: Copy, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "the lower bound is greater than the upper one");
+
+    /// Return the value as the inner integer.
+    #[inline]
+    pub const fn get(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! bounded_impls {
+    ($($int:ident),*) => {
+        $(
+            impl<const MIN: i128, const MAX: i128> Bounded<$int, MIN, MAX> {
+                /// Create a value from an integer, returning `None` if it is out of the range.
+                #[inline]
+                pub const fn new(n: $int) -> Option<Self> {
+                    let () = Self::VALID_BOUNDS;
+                    if n as i128 >= MIN && n as i128 <= MAX {
+                        Some(Bounded(n))
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> fmt::Display for Bounded<$int, MIN, MAX> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> TryFrom<$int> for Bounded<$int, MIN, MAX> {
+                type Error = OutOfBounds;
+
+                #[inline]
+                fn try_from(n: $int) -> Result<Self, OutOfBounds> {
+                    Self::new(n).ok_or(OutOfBounds {
+                        value: n as i128,
+                        min: MIN,
+                        max: MAX,
+                    })
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> From<Bounded<$int, MIN, MAX>> for $int {
+                #[inline]
+                fn from(n: Bounded<$int, MIN, MAX>) -> Self {
+                    n.0
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> TryFromBytes for Bounded<$int, MIN, MAX> {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+                type Error = OutOfBounds;
+
+                #[inline]
+                fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, OutOfBounds> {
+                    Self::try_from($int::from_le_bytes(bytes))
+                }
+
+                #[inline]
+                fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, OutOfBounds> {
+                    Self::try_from($int::from_be_bytes(bytes))
+                }
+            }
+
+            impl<const MIN: i128, const MAX: i128> ToBytes for Bounded<$int, MIN, MAX> {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    self.0.to_le_bytes()
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    self.0.to_be_bytes()
+                }
+            }
+        )*
+    };
+}
+
+bounded_impls!(u8, i8, u16, i16, u32, i32, u64, i64, i128);
@@ -0,0 +1,161 @@
+/// Reading and writing packed values with the buffers of the `bytes` crate.
+///
+/// The values are copied chunk by chunk, so buffers whose bytes are not contiguous in memory,
+/// such as chained buffers, are read and written correctly.
+///
+/// ```
+/// use bytes::{Buf, BytesMut};
+/// use packbytes::ext::bytes::{BufMutPackedExt, BufPackedExt};
+///
+/// let mut buf = BytesMut::new();
+/// buf.put_packed(1u16);
+/// buf.put_packed_be(2u16);
+/// assert_eq!(buf, [1, 0, 0, 2][..]);
+///
+/// let mut buf = buf.freeze().chain(&[1][..]);
+/// assert_eq!(buf.get_packed::<u16>(), 1);
+/// assert_eq!(buf.get_packed_be::<u16>(), 2);
+/// assert_eq!(buf.try_get_packed::<bool>(), Ok(true));
+/// assert!(buf.try_get_packed::<bool>().is_err());
+/// ```
+#[cfg(feature = "bytes")]
+pub mod bytes {
+    use crate::error::{NotEnoughBytes, SliceError};
+    use crate::{ByteArray, FromBytes, ToBytes, TryFromBytes, TryToBytes};
+    use ::bytes::{Buf, BufMut};
+
+    /// Copy a byte array of the type `B` from the buffer, or return `None` if it's too short.
+    fn get_bytes<B: ByteArray, R: Buf + ?Sized>(buf: &mut R) -> Option<B> {
+        if buf.remaining() < B::SIZE {
+            return None;
+        }
+        let mut bytes = B::zeroed();
+        buf.copy_to_slice(bytes.as_mut());
+        Some(bytes)
+    }
+
+    /// Copy a byte array of the type `B` from the buffer, panicking if it's too short.
+    fn expect_bytes<B: ByteArray, R: Buf + ?Sized>(buf: &mut R) -> B {
+        get_bytes(buf).expect("not enough remaining bytes in the buffer")
+    }
+
+    /// Copy a byte array of the type `B` from the buffer, returning an error if it's too short.
+    fn try_get_bytes<B: ByteArray, E, R: Buf + ?Sized>(buf: &mut R) -> Result<B, SliceError<E>> {
+        let remaining = buf.remaining();
+        get_bytes(buf).ok_or(SliceError::NotEnoughBytes(NotEnoughBytes {
+            needed: B::SIZE,
+            remaining,
+        }))
+    }
+
+    /// Extension methods for getting packed values from a buffer, implemented for every `Buf`.
+    ///
+    /// Like the `get_*` methods of `Buf`, the methods which can't fail panic if there are not
+    /// enough remaining bytes. The `try_get_*` methods return an error instead, without advancing
+    /// the buffer. If the bytes don't represent a valid value, they are consumed nevertheless:
+    /// a buffer made of several chunks can't be read without advancing it, unlike a `Decoder`.
+    pub trait BufPackedExt: Buf {
+        /// Get a value in its preferred byte order.
+        ///
+        /// Panics if there are not enough remaining bytes.
+        #[inline]
+        fn get_packed<T: FromBytes>(&mut self) -> T {
+            T::from_bytes(expect_bytes(self))
+        }
+
+        /// Get a value in the little endian byte order.
+        ///
+        /// Panics if there are not enough remaining bytes.
+        #[inline]
+        fn get_packed_le<T: FromBytes>(&mut self) -> T {
+            T::from_le_bytes(expect_bytes(self))
+        }
+
+        /// Get a value in the big endian byte order.
+        ///
+        /// Panics if there are not enough remaining bytes.
+        #[inline]
+        fn get_packed_be<T: FromBytes>(&mut self) -> T {
+            T::from_be_bytes(expect_bytes(self))
+        }
+
+        /// Try to get a value in its preferred byte order.
+        ///
+        /// If the bytes don't represent a valid value, the buffer is still advanced past them.
+        #[inline]
+        fn try_get_packed<T: TryFromBytes>(&mut self) -> Result<T, SliceError<T::Error>> {
+            T::try_from_bytes(try_get_bytes(self)?).map_err(SliceError::Invalid)
+        }
+
+        /// Try to get a value in the little endian byte order.
+        ///
+        /// If the bytes don't represent a valid value, the buffer is still advanced past them.
+        #[inline]
+        fn try_get_packed_le<T: TryFromBytes>(&mut self) -> Result<T, SliceError<T::Error>> {
+            T::try_from_le_bytes(try_get_bytes(self)?).map_err(SliceError::Invalid)
+        }
+
+        /// Try to get a value in the big endian byte order.
+        ///
+        /// If the bytes don't represent a valid value, the buffer is still advanced past them.
+        #[inline]
+        fn try_get_packed_be<T: TryFromBytes>(&mut self) -> Result<T, SliceError<T::Error>> {
+            T::try_from_be_bytes(try_get_bytes(self)?).map_err(SliceError::Invalid)
+        }
+    }
+
+    impl<R: Buf + ?Sized> BufPackedExt for R {}
+
+    /// Extension methods for putting packed values into a buffer, implemented for every `BufMut`.
+    ///
+    /// Like the `put_*` methods of `BufMut`, the methods panic if there is not enough remaining
+    /// space in the buffer.
+    pub trait BufMutPackedExt: BufMut {
+        /// Put a value in its preferred byte order.
+        #[inline]
+        fn put_packed<T: ToBytes>(&mut self, value: T) {
+            self.put_slice(value.to_bytes().as_ref())
+        }
+
+        /// Put a value in the little endian byte order.
+        #[inline]
+        fn put_packed_le<T: ToBytes>(&mut self, value: T) {
+            self.put_slice(value.to_le_bytes().as_ref())
+        }
+
+        /// Put a value in the big endian byte order.
+        #[inline]
+        fn put_packed_be<T: ToBytes>(&mut self, value: T) {
+            self.put_slice(value.to_be_bytes().as_ref())
+        }
+
+        /// Try to put a value in its preferred byte order.
+        ///
+        /// If the value can't be packed, nothing is put into the buffer.
+        #[inline]
+        fn try_put_packed<T: TryToBytes>(&mut self, value: T) -> Result<(), T::Error> {
+            self.put_slice(value.try_to_bytes()?.as_ref());
+            Ok(())
+        }
+
+        /// Try to put a value in the little endian byte order.
+        ///
+        /// If the value can't be packed, nothing is put into the buffer.
+        #[inline]
+        fn try_put_packed_le<T: TryToBytes>(&mut self, value: T) -> Result<(), T::Error> {
+            self.put_slice(value.try_to_le_bytes()?.as_ref());
+            Ok(())
+        }
+
+        /// Try to put a value in the big endian byte order.
+        ///
+        /// If the value can't be packed, nothing is put into the buffer.
+        #[inline]
+        fn try_put_packed_be<T: TryToBytes>(&mut self, value: T) -> Result<(), T::Error> {
+            self.put_slice(value.try_to_be_bytes()?.as_ref());
+            Ok(())
+        }
+    }
+
+    impl<W: BufMut + ?Sized> BufMutPackedExt for W {}
+}
@@ -0,0 +1,128 @@
+use crate::error::{NotEnoughBytes, NotEnoughSpace};
+use crate::{ByteArray, FromBytes, ToBytes};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// An iterator decoding values of the type `T` packed one after another in a slice, created by
+/// the `decode_slice` method.
+///
+/// The values are read in the preferred byte order of the type. The bytes at the end of the slice
+/// that don't form a whole value are not decoded; they can be obtained by `remainder`.
+///
+/// ```
+/// use packbytes::FromBytes;
+///
+/// let mut iter = u16::decode_slice(&[1, 0, 2, 0, 3]);
+/// assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2]);
+/// assert_eq!(iter.remainder(), [3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecodeSlice<'a, T> {
+    bytes: &'a [u8],
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: FromBytes> DecodeSlice<'a, T> {
+    /// Create an iterator decoding the values from the slice.
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        DecodeSlice {
+            bytes,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the bytes at the end of the slice that don't form a whole value.
+    pub fn remainder(&self) -> &'a [u8] {
+        match T::Bytes::SIZE {
+            0 => self.bytes,
+            size => &self.bytes[self.bytes.len() / size * size..],
+        }
+    }
+}
+
+impl<T: FromBytes> Iterator for DecodeSlice<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let size = T::Bytes::SIZE;
+        if size == 0 || self.bytes.len() < size {
+            return None;
+        }
+        let (head, rest) = self.bytes.split_at(size);
+        let mut bytes = T::Bytes::zeroed();
+        bytes.as_mut().copy_from_slice(head);
+        self.bytes = rest;
+        Some(T::from_bytes(bytes))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len().checked_div(T::Bytes::SIZE).unwrap_or(0);
+        (len, Some(len))
+    }
+}
+
+impl<T: FromBytes> ExactSizeIterator for DecodeSlice<'_, T> {}
+
+impl<T: FromBytes> FusedIterator for DecodeSlice<'_, T> {}
+
+/// Decode values packed one after another by the given function, filling the output slice.
+#[inline]
+pub(crate) fn decode_each<T: FromBytes>(
+    bytes: &[u8],
+    out: &mut [T],
+    from_bytes: impl Fn(T::Bytes) -> T,
+) -> Result<usize, NotEnoughBytes> {
+    let size = T::Bytes::SIZE;
+    let needed = out.len() * size;
+    if bytes.len() < needed {
+        return Err(NotEnoughBytes {
+            needed,
+            remaining: bytes.len(),
+        });
+    }
+    for (i, val) in out.iter_mut().enumerate() {
+        *val = from_bytes(bytes_at::<T::Bytes>(bytes, i));
+    }
+    Ok(needed)
+}
+
+/// Decode an array of values packed one after another by the given function.
+#[inline]
+pub(crate) fn decode_array<T: FromBytes, const N: usize>(
+    bytes: &[u8],
+    from_bytes: impl Fn(T::Bytes) -> T,
+) -> [T; N] {
+    core::array::from_fn(|i| from_bytes(bytes_at::<T::Bytes>(bytes, i)))
+}
+
+/// Encode values one after another by the given function at the beginning of the output slice.
+#[inline]
+pub(crate) fn encode_each<T: ToBytes + Clone>(
+    values: &[T],
+    out: &mut [u8],
+    to_bytes: impl Fn(T) -> T::Bytes,
+) -> Result<usize, NotEnoughSpace> {
+    let size = T::Bytes::SIZE;
+    let needed = values.len() * size;
+    if out.len() < needed {
+        return Err(NotEnoughSpace {
+            needed,
+            remaining: out.len(),
+        });
+    }
+    for (i, val) in values.iter().enumerate() {
+        out[i * size..(i + 1) * size].copy_from_slice(to_bytes(val.clone()).as_ref());
+    }
+    Ok(needed)
+}
+
+/// Return the bytes of the value with the given index in a slice of packed values.
+#[inline]
+fn bytes_at<B: ByteArray>(bytes: &[u8], index: usize) -> B {
+    let mut b = B::zeroed();
+    b.as_mut()
+        .copy_from_slice(&bytes[index * B::SIZE..(index + 1) * B::SIZE]);
+    b
+}
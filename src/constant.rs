@@ -0,0 +1,110 @@
+use crate::error::InvalidConst;
+use crate::{ToBytes, TryFromBytes};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A field that always holds the value `V`, packed as the integer type `T`.
+///
+/// It takes no space in memory. When packing, the constant is written, and when reading,
+/// any other value fails with [`InvalidConst`]. This is useful for versions, lengths of fixed
+/// headers and similar fields. Like [`Bounded`](crate::Bounded), it is implemented for
+/// the fixed-size primitive integers except `u128`, and the value needs to fit into `T`.
+///
+/// ```
+/// use packbytes::{Const, ToBytes, TryFromBytes};
+///
+/// type Version = Const<u16, 1>;
+///
+/// assert_eq!(Version::try_from_be_bytes([0, 1]), Ok(Version::new()));
+/// assert!(Version::try_from_be_bytes([0, 2]).is_err());
+/// assert_eq!(Version::new().to_be_bytes(), [0, 1]);
+/// assert_eq!(core::mem::size_of::<Version>(), 0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Const<T, const V: i128>(PhantomData<T>);
+
+impl<T, const V: i128> Const<T, V> {
+    /// Create the constant.
+    #[inline]
+    pub const fn new() -> Self {
+        Const(PhantomData)
+    }
+}
+
+impl<T, const V: i128> Default for Const<T, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! const_impls {
+    ($($int:ident),*) => {
+        $(
+            impl<const V: i128> Const<$int, V> {
+                /// The value of the constant.
+                pub const VALUE: $int = {
+                    assert!(V >= $int::MIN as i128 && V <= $int::MAX as i128);
+                    V as $int
+                };
+
+                /// Return the value of the constant.
+                #[inline]
+                pub const fn get(self) -> $int {
+                    Self::VALUE
+                }
+            }
+
+            impl<const V: i128> fmt::Debug for Const<$int, V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "Const({})", Self::VALUE)
+                }
+            }
+
+            impl<const V: i128> fmt::Display for Const<$int, V> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&Self::VALUE, f)
+                }
+            }
+
+            impl<const V: i128> TryFromBytes for Const<$int, V> {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+                type Error = InvalidConst;
+
+                #[inline]
+                fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, InvalidConst> {
+                    if $int::from_le_bytes(bytes) == Self::VALUE {
+                        Ok(Self::new())
+                    } else {
+                        Err(InvalidConst)
+                    }
+                }
+
+                #[inline]
+                fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, InvalidConst> {
+                    if $int::from_be_bytes(bytes) == Self::VALUE {
+                        Ok(Self::new())
+                    } else {
+                        Err(InvalidConst)
+                    }
+                }
+            }
+
+            impl<const V: i128> ToBytes for Const<$int, V> {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    Self::VALUE.to_le_bytes()
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    Self::VALUE.to_be_bytes()
+                }
+            }
+        )*
+    };
+}
+
+const_impls!(u8, i8, u16, i16, u32, i32, u64, i64, i128);
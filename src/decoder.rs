@@ -0,0 +1,137 @@
+use crate::error::{NotEnoughBytes, SliceError};
+use crate::{ByteArray, FromBytes, TryFromBytes};
+
+/// A cursor reading packed values from a byte slice one after another.
+///
+/// It doesn't need `std` nor `alloc`, so it can replace `read_packed` in `no_std` environments.
+/// The values are read in their preferred byte order.
+///
+/// ```
+/// use packbytes::Decoder;
+///
+/// let mut decoder = Decoder::new(&[1, 0xff, 2, 0, 0, 0, 3]);
+/// assert_eq!(decoder.read::<u8>(), Ok(1));
+/// decoder.align_to(2).unwrap();
+/// assert_eq!(decoder.peek::<u16>(), Ok(2));
+/// assert_eq!(decoder.read::<u32>(), Ok(2));
+/// assert_eq!(decoder.remaining(), 1);
+/// assert!(decoder.read::<u16>().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Create a decoder reading from the start of the slice.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Decoder { bytes, pos: 0 }
+    }
+
+    /// Return the number of bytes read so far.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Return the number of bytes that remain to be read.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    /// Have all the bytes been read?
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Return the bytes that remain to be read.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    /// Read a value.
+    #[inline]
+    pub fn read<T: FromBytes>(&mut self) -> Result<T, NotEnoughBytes> {
+        let val = self.peek()?;
+        self.pos += T::Bytes::SIZE;
+        Ok(val)
+    }
+
+    /// Try to read a value. If it fails, the position is not changed.
+    #[inline]
+    pub fn try_read<T: TryFromBytes>(&mut self) -> Result<T, SliceError<T::Error>> {
+        let val = self.try_peek()?;
+        self.pos += T::Bytes::SIZE;
+        Ok(val)
+    }
+
+    /// Read a value without advancing the position.
+    #[inline]
+    pub fn peek<T: FromBytes>(&self) -> Result<T, NotEnoughBytes> {
+        Ok(T::from_bytes(self.peek_bytes()?))
+    }
+
+    /// Try to read a value without advancing the position.
+    #[inline]
+    pub fn try_peek<T: TryFromBytes>(&self) -> Result<T, SliceError<T::Error>> {
+        T::try_from_bytes(self.peek_bytes()?).map_err(SliceError::Invalid)
+    }
+
+    /// Read a slice of `n` bytes.
+    #[inline]
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], NotEnoughBytes> {
+        self.check(n)?;
+        let bytes = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    /// Skip `n` bytes.
+    #[inline]
+    pub fn skip(&mut self, n: usize) -> Result<(), NotEnoughBytes> {
+        self.check(n)?;
+        self.pos += n;
+        Ok(())
+    }
+
+    /// Is the position a multiple of `align`?
+    ///
+    /// Panics if `align` is zero.
+    #[inline]
+    pub const fn is_aligned(&self, align: usize) -> bool {
+        self.pos % align == 0
+    }
+
+    /// Skip the bytes up to the next position that is a multiple of `align`.
+    ///
+    /// Panics if `align` is zero.
+    #[inline]
+    pub fn align_to(&mut self, align: usize) -> Result<(), NotEnoughBytes> {
+        self.skip(self.pos.next_multiple_of(align) - self.pos)
+    }
+
+    /// Copy the byte array of the type `B` from the current position.
+    fn peek_bytes<B: ByteArray>(&self) -> Result<B, NotEnoughBytes> {
+        self.check(B::SIZE)?;
+        let mut bytes = B::zeroed();
+        bytes
+            .as_mut()
+            .copy_from_slice(&self.bytes[self.pos..self.pos + B::SIZE]);
+        Ok(bytes)
+    }
+
+    /// Check that at least `needed` bytes remain.
+    #[inline]
+    fn check(&self, needed: usize) -> Result<(), NotEnoughBytes> {
+        let remaining = self.remaining();
+        if needed > remaining {
+            return Err(NotEnoughBytes { needed, remaining });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,317 @@
+/// Reading and writing packed values with the I/O traits of `embedded-io`, without `std`.
+///
+/// ```
+/// use packbytes::ext::embedded_io::{ReadPackedExt, WritePackedExt};
+///
+/// let mut buf = [0; 4];
+/// let mut writer = &mut buf[..];
+/// writer.write_packed(1u16).unwrap();
+/// writer.write_packed_be(2u16).unwrap();
+/// assert_eq!(buf, [1, 0, 0, 2]);
+///
+/// let mut reader = &buf[..];
+/// assert_eq!(reader.read_packed::<u16>().unwrap(), 1);
+/// assert!(!reader.try_read_packed::<bool>().unwrap());
+/// ```
+#[cfg(feature = "embedded-io")]
+pub mod embedded_io {
+    use crate::error::{ReadPackedError, WritePackedError};
+    use crate::{ByteArray, FromBytes, ToBytes, TryFromBytes, TryToBytes};
+    use ::embedded_io::{Read, ReadExactError, Write};
+
+    /// Read a byte array of the type `B` from a reader.
+    fn read_bytes<B: ByteArray, R: Read + ?Sized>(
+        reader: &mut R,
+    ) -> Result<B, ReadExactError<R::Error>> {
+        let mut bytes = B::zeroed();
+        reader.read_exact(bytes.as_mut())?;
+        Ok(bytes)
+    }
+
+    /// Extension methods for reading packed values, implemented for every reader.
+    ///
+    /// Each method reads exactly the bytes of one value by a single `read_exact`.
+    pub trait ReadPackedExt: Read {
+        /// Read a value in its preferred byte order.
+        #[inline]
+        fn read_packed<T: FromBytes>(&mut self) -> Result<T, ReadExactError<Self::Error>> {
+            read_bytes(self).map(T::from_bytes)
+        }
+
+        /// Read a value in the little endian byte order.
+        #[inline]
+        fn read_packed_le<T: FromBytes>(&mut self) -> Result<T, ReadExactError<Self::Error>> {
+            read_bytes(self).map(T::from_le_bytes)
+        }
+
+        /// Read a value in the big endian byte order.
+        #[inline]
+        fn read_packed_be<T: FromBytes>(&mut self) -> Result<T, ReadExactError<Self::Error>> {
+            read_bytes(self).map(T::from_be_bytes)
+        }
+
+        /// Try to read a value in its preferred byte order.
+        #[inline]
+        fn try_read_packed<T: TryFromBytes>(
+            &mut self,
+        ) -> Result<T, ReadPackedError<Self::Error, T::Error>> {
+            T::try_from_bytes(read_bytes(self)?).map_err(ReadPackedError::Invalid)
+        }
+
+        /// Try to read a value in the little endian byte order.
+        #[inline]
+        fn try_read_packed_le<T: TryFromBytes>(
+            &mut self,
+        ) -> Result<T, ReadPackedError<Self::Error, T::Error>> {
+            T::try_from_le_bytes(read_bytes(self)?).map_err(ReadPackedError::Invalid)
+        }
+
+        /// Try to read a value in the big endian byte order.
+        #[inline]
+        fn try_read_packed_be<T: TryFromBytes>(
+            &mut self,
+        ) -> Result<T, ReadPackedError<Self::Error, T::Error>> {
+            T::try_from_be_bytes(read_bytes(self)?).map_err(ReadPackedError::Invalid)
+        }
+    }
+
+    impl<R: Read + ?Sized> ReadPackedExt for R {}
+
+    /// Extension methods for writing packed values, implemented for every writer.
+    pub trait WritePackedExt: Write {
+        /// Write a value in its preferred byte order.
+        #[inline]
+        fn write_packed<T: ToBytes>(&mut self, value: T) -> Result<(), Self::Error> {
+            self.write_all(value.to_bytes().as_ref())
+        }
+
+        /// Write a value in the little endian byte order.
+        #[inline]
+        fn write_packed_le<T: ToBytes>(&mut self, value: T) -> Result<(), Self::Error> {
+            self.write_all(value.to_le_bytes().as_ref())
+        }
+
+        /// Write a value in the big endian byte order.
+        #[inline]
+        fn write_packed_be<T: ToBytes>(&mut self, value: T) -> Result<(), Self::Error> {
+            self.write_all(value.to_be_bytes().as_ref())
+        }
+
+        /// Try to write a value in its preferred byte order.
+        #[inline]
+        fn try_write_packed<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> Result<(), WritePackedError<Self::Error, T::Error>> {
+            let bytes = value.try_to_bytes().map_err(WritePackedError::Invalid)?;
+            self.write_all(bytes.as_ref()).map_err(WritePackedError::Io)
+        }
+
+        /// Try to write a value in the little endian byte order.
+        #[inline]
+        fn try_write_packed_le<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> Result<(), WritePackedError<Self::Error, T::Error>> {
+            let bytes = value.try_to_le_bytes().map_err(WritePackedError::Invalid)?;
+            self.write_all(bytes.as_ref()).map_err(WritePackedError::Io)
+        }
+
+        /// Try to write a value in the big endian byte order.
+        #[inline]
+        fn try_write_packed_be<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> Result<(), WritePackedError<Self::Error, T::Error>> {
+            let bytes = value.try_to_be_bytes().map_err(WritePackedError::Invalid)?;
+            self.write_all(bytes.as_ref()).map_err(WritePackedError::Io)
+        }
+    }
+
+    impl<W: Write + ?Sized> WritePackedExt for W {}
+}
+
+/// Reading and writing packed values asynchronously with the I/O traits of `embedded-io-async`,
+/// without `std`.
+///
+/// ```
+/// # async fn run() {
+/// use packbytes::ext::embedded_io_async::{AsyncReadPackedExt, AsyncWritePackedExt};
+///
+/// let mut buf = [0; 4];
+/// let mut writer = &mut buf[..];
+/// writer.write_packed_async(1u16).await.unwrap();
+/// writer.write_packed_be_async(2u16).await.unwrap();
+/// assert_eq!(buf, [1, 0, 0, 2]);
+///
+/// let mut reader = &buf[..];
+/// assert_eq!(reader.read_packed_async::<u16>().await.unwrap(), 1);
+/// # }
+/// ```
+#[cfg(feature = "embedded-io-async")]
+pub mod embedded_io_async {
+    use crate::error::{ReadPackedError, WritePackedError};
+    use crate::{ByteArray, FromBytes, ToBytes, TryFromBytes, TryToBytes};
+    use ::embedded_io_async::{ErrorType, Read, ReadExactError, Write};
+    use core::future::Future;
+
+    /// Read a byte array of the type `B` from a reader.
+    async fn read_bytes<B: ByteArray, R: Read + ?Sized>(
+        reader: &mut R,
+    ) -> Result<B, ReadExactError<R::Error>> {
+        let mut bytes = B::zeroed();
+        reader.read_exact(bytes.as_mut()).await?;
+        Ok(bytes)
+    }
+
+    /// The result of reading a value of the type `T` from the reader `R`.
+    type ReadResult<T, R> = Result<T, ReadExactError<<R as ErrorType>::Error>>;
+    /// The result of trying to read a value of the type `T` from the reader `R`.
+    type TryReadResult<T, R> =
+        Result<T, ReadPackedError<<R as ErrorType>::Error, <T as TryFromBytes>::Error>>;
+    /// The result of trying to write a value of the type `T` to the writer `W`.
+    type TryWriteResult<T, W> =
+        Result<(), WritePackedError<<W as ErrorType>::Error, <T as TryToBytes>::Error>>;
+
+    /// Extension methods for reading packed values asynchronously, implemented for every reader.
+    ///
+    /// Each method reads exactly the bytes of one value by a single `read_exact`.
+    pub trait AsyncReadPackedExt: Read {
+        /// Read a value in its preferred byte order.
+        fn read_packed_async<T: FromBytes>(&mut self) -> impl Future<Output = ReadResult<T, Self>>;
+
+        /// Read a value in the little endian byte order.
+        fn read_packed_le_async<T: FromBytes>(
+            &mut self,
+        ) -> impl Future<Output = ReadResult<T, Self>>;
+
+        /// Read a value in the big endian byte order.
+        fn read_packed_be_async<T: FromBytes>(
+            &mut self,
+        ) -> impl Future<Output = ReadResult<T, Self>>;
+
+        /// Try to read a value in its preferred byte order.
+        fn try_read_packed_async<T: TryFromBytes>(
+            &mut self,
+        ) -> impl Future<Output = TryReadResult<T, Self>>;
+
+        /// Try to read a value in the little endian byte order.
+        fn try_read_packed_le_async<T: TryFromBytes>(
+            &mut self,
+        ) -> impl Future<Output = TryReadResult<T, Self>>;
+
+        /// Try to read a value in the big endian byte order.
+        fn try_read_packed_be_async<T: TryFromBytes>(
+            &mut self,
+        ) -> impl Future<Output = TryReadResult<T, Self>>;
+    }
+
+    impl<R: Read + ?Sized> AsyncReadPackedExt for R {
+        async fn read_packed_async<T: FromBytes>(&mut self) -> ReadResult<T, Self> {
+            read_bytes(self).await.map(T::from_bytes)
+        }
+
+        async fn read_packed_le_async<T: FromBytes>(&mut self) -> ReadResult<T, Self> {
+            read_bytes(self).await.map(T::from_le_bytes)
+        }
+
+        async fn read_packed_be_async<T: FromBytes>(&mut self) -> ReadResult<T, Self> {
+            read_bytes(self).await.map(T::from_be_bytes)
+        }
+
+        async fn try_read_packed_async<T: TryFromBytes>(&mut self) -> TryReadResult<T, Self> {
+            T::try_from_bytes(read_bytes(self).await?).map_err(ReadPackedError::Invalid)
+        }
+
+        async fn try_read_packed_le_async<T: TryFromBytes>(&mut self) -> TryReadResult<T, Self> {
+            T::try_from_le_bytes(read_bytes(self).await?).map_err(ReadPackedError::Invalid)
+        }
+
+        async fn try_read_packed_be_async<T: TryFromBytes>(&mut self) -> TryReadResult<T, Self> {
+            T::try_from_be_bytes(read_bytes(self).await?).map_err(ReadPackedError::Invalid)
+        }
+    }
+
+    /// Extension methods for writing packed values asynchronously, implemented for every writer.
+    pub trait AsyncWritePackedExt: Write {
+        /// Write a value in its preferred byte order.
+        fn write_packed_async<T: ToBytes>(
+            &mut self,
+            value: T,
+        ) -> impl Future<Output = Result<(), Self::Error>>;
+
+        /// Write a value in the little endian byte order.
+        fn write_packed_le_async<T: ToBytes>(
+            &mut self,
+            value: T,
+        ) -> impl Future<Output = Result<(), Self::Error>>;
+
+        /// Write a value in the big endian byte order.
+        fn write_packed_be_async<T: ToBytes>(
+            &mut self,
+            value: T,
+        ) -> impl Future<Output = Result<(), Self::Error>>;
+
+        /// Try to write a value in its preferred byte order.
+        fn try_write_packed_async<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> impl Future<Output = TryWriteResult<T, Self>>;
+
+        /// Try to write a value in the little endian byte order.
+        fn try_write_packed_le_async<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> impl Future<Output = TryWriteResult<T, Self>>;
+
+        /// Try to write a value in the big endian byte order.
+        fn try_write_packed_be_async<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> impl Future<Output = TryWriteResult<T, Self>>;
+    }
+
+    impl<W: Write + ?Sized> AsyncWritePackedExt for W {
+        async fn write_packed_async<T: ToBytes>(&mut self, value: T) -> Result<(), Self::Error> {
+            self.write_all(value.to_bytes().as_ref()).await
+        }
+
+        async fn write_packed_le_async<T: ToBytes>(&mut self, value: T) -> Result<(), Self::Error> {
+            self.write_all(value.to_le_bytes().as_ref()).await
+        }
+
+        async fn write_packed_be_async<T: ToBytes>(&mut self, value: T) -> Result<(), Self::Error> {
+            self.write_all(value.to_be_bytes().as_ref()).await
+        }
+
+        async fn try_write_packed_async<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> TryWriteResult<T, Self> {
+            let bytes = value.try_to_bytes().map_err(WritePackedError::Invalid)?;
+            self.write_all(bytes.as_ref())
+                .await
+                .map_err(WritePackedError::Io)
+        }
+
+        async fn try_write_packed_le_async<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> TryWriteResult<T, Self> {
+            let bytes = value.try_to_le_bytes().map_err(WritePackedError::Invalid)?;
+            self.write_all(bytes.as_ref())
+                .await
+                .map_err(WritePackedError::Io)
+        }
+
+        async fn try_write_packed_be_async<T: TryToBytes>(
+            &mut self,
+            value: T,
+        ) -> TryWriteResult<T, Self> {
+            let bytes = value.try_to_be_bytes().map_err(WritePackedError::Invalid)?;
+            self.write_all(bytes.as_ref())
+                .await
+                .map_err(WritePackedError::Io)
+        }
+    }
+}
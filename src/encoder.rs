@@ -0,0 +1,154 @@
+use crate::error::NotEnoughSpace;
+use crate::{ByteArray, ToBytes};
+use core::marker::PhantomData;
+
+/// A cursor writing packed values into a mutable byte slice one after another.
+///
+/// It doesn't need `std` nor `alloc`, so it can replace `write_packed` in `no_std` environments.
+/// The values are written in their preferred byte order.
+///
+/// Space for a value that is not known yet (such as the length of what follows) can be reserved
+/// and filled in later.
+///
+/// ```
+/// use packbytes::Encoder;
+///
+/// let mut buffer = [0; 8];
+/// let mut encoder = Encoder::new(&mut buffer);
+/// encoder.write(0xffu8).unwrap();
+/// let length = encoder.reserve::<u16>().unwrap();
+/// encoder.write_bytes(b"abc").unwrap();
+/// encoder.fill(length, 3);
+/// assert!(encoder.write(0u32).is_err());
+/// assert_eq!(encoder.finish(), [0xff, 3, 0, b'a', b'b', b'c']);
+/// ```
+#[derive(Debug)]
+pub struct Encoder<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+/// Space reserved in an [`Encoder`] for a value of the type `T`, to be filled in later.
+///
+/// It borrows nothing, but its lifetime is the one of the encoder's buffer, so it can't outlive
+/// the buffer. It remembers the address of the buffer, so [`Encoder::fill`] panics if it's
+/// passed to an encoder writing somewhere else.
+#[derive(Debug)]
+#[must_use = "the reserved space should be filled in"]
+pub struct Reserved<'a, T> {
+    buf: usize,
+    pos: usize,
+    ty: PhantomData<(&'a [u8], T)>,
+}
+
+impl<T> Reserved<'_, T> {
+    /// Return the position of the reserved space in the buffer.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> Encoder<'a> {
+    /// Create an encoder writing to the start of the buffer.
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Encoder { buf, pos: 0 }
+    }
+
+    /// Return the number of bytes written so far.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Return the number of bytes that can still be written.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Write a value.
+    #[inline]
+    pub fn write<T: ToBytes>(&mut self, value: T) -> Result<(), NotEnoughSpace> {
+        self.check(T::Bytes::SIZE)?;
+        self.write_bytes(value.to_bytes().as_ref())
+    }
+
+    /// Write a slice of bytes.
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), NotEnoughSpace> {
+        self.check(bytes.len())?;
+        self.buf[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+        Ok(())
+    }
+
+    /// Reserve space for a value of the type `T`, to be filled in later by `fill`.
+    /// Until then, the space is filled with zeros.
+    #[inline]
+    pub fn reserve<T: ToBytes>(&mut self) -> Result<Reserved<'a, T>, NotEnoughSpace> {
+        let pos = self.pos;
+        self.pad(T::Bytes::SIZE)?;
+        Ok(Reserved {
+            buf: self.buf.as_ptr() as usize,
+            pos,
+            ty: PhantomData,
+        })
+    }
+
+    /// Write a value into the space reserved for it.
+    ///
+    /// Panics if the space was reserved in an encoder writing to a different buffer.
+    #[inline]
+    pub fn fill<T: ToBytes>(&mut self, reserved: Reserved<'a, T>, value: T) {
+        assert_eq!(
+            reserved.buf,
+            self.buf.as_ptr() as usize,
+            "the space was reserved in a different buffer"
+        );
+        self.buf[reserved.pos..reserved.pos + T::Bytes::SIZE]
+            .copy_from_slice(value.to_bytes().as_ref());
+    }
+
+    /// Write `n` zero bytes.
+    #[inline]
+    pub fn pad(&mut self, n: usize) -> Result<(), NotEnoughSpace> {
+        self.check(n)?;
+        self.buf[self.pos..self.pos + n].fill(0);
+        self.pos += n;
+        Ok(())
+    }
+
+    /// Is the position a multiple of `align`?
+    ///
+    /// Panics if `align` is zero.
+    #[inline]
+    pub const fn is_aligned(&self, align: usize) -> bool {
+        self.pos % align == 0
+    }
+
+    /// Write zero bytes up to the next position that is a multiple of `align`.
+    ///
+    /// Panics if `align` is zero.
+    #[inline]
+    pub fn align_to(&mut self, align: usize) -> Result<(), NotEnoughSpace> {
+        self.pad(self.pos.next_multiple_of(align) - self.pos)
+    }
+
+    /// Return the written part of the buffer.
+    #[inline]
+    pub fn finish(self) -> &'a [u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Check that at least `needed` bytes remain.
+    #[inline]
+    fn check(&self, needed: usize) -> Result<(), NotEnoughSpace> {
+        let remaining = self.remaining();
+        if needed > remaining {
+            return Err(NotEnoughSpace { needed, remaining });
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use core::convert::Infallible;
-use core::fmt::{Display, Formatter, Result};
+use core::fmt::{Debug, Display, Formatter, Result};
 
 /// A general error signaling an attempt to construct an value from an invalid byte representation.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -15,6 +15,105 @@ pub struct InvalidChar;
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct InvalidBool;
 
+/// An error signaling an attempt to construct a value of a non-zero integer type (such as
+/// `NonZeroU32`) from a byte representation of zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidNonZero;
+
+/// An error signaling an attempt to construct a value from a number outside of its range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange;
+
+/// An error signaling an attempt to construct a bounded integer from a number outside of its
+/// bounds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The rejected number.
+    pub value: i128,
+    /// The lower bound, inclusive.
+    pub min: i128,
+    /// The upper bound, inclusive.
+    pub max: i128,
+}
+
+/// An error signaling an attempt to construct a string from bytes that are not valid in its
+/// encoding, such as UTF-8.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidStr;
+
+/// An error signaling an attempt to store a string in a buffer too short for it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StrTooLong;
+
+/// An error of creating a string of a fixed encoding, such as Latin-1, from a string slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeStrError {
+    /// The string was too long to be stored.
+    TooLong(StrTooLong),
+    /// The string contained a character not representable in the encoding.
+    Invalid(InvalidStr),
+}
+
+/// An error signaling bytes that don't represent the value of a constant field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidConst;
+
+/// An error signaling an attempt to parse a GUID from a string not in the canonical form.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParseGuidError;
+
+/// An error of creating a value from a slice of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError<E> {
+    /// The slice was shorter than the byte representation.
+    NotEnoughBytes(NotEnoughBytes),
+    /// The slice was longer than the byte representation by `extra` bytes.
+    TrailingBytes {
+        /// The number of bytes after the byte representation.
+        extra: usize,
+    },
+    /// The bytes didn't represent a valid value.
+    Invalid(E),
+}
+
+/// An error of reading a packed value from a reader of the `embedded-io` traits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadPackedError<E, V> {
+    /// The reader ended before all the bytes of the value were read.
+    UnexpectedEof,
+    /// The reader failed with an error.
+    Io(E),
+    /// The bytes didn't represent a valid value.
+    Invalid(V),
+}
+
+/// An error of writing a packed value to a writer of the `embedded-io` traits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WritePackedError<E, V> {
+    /// The writer failed with an error.
+    Io(E),
+    /// The value couldn't be packed.
+    Invalid(V),
+}
+
+/// An error signaling an attempt to read more bytes than remain in the input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NotEnoughBytes {
+    /// The number of bytes that were needed.
+    pub needed: usize,
+    /// The number of bytes that remained.
+    pub remaining: usize,
+}
+
+/// An error signaling an attempt to write more bytes than fit into the remaining space of a buffer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NotEnoughSpace {
+    /// The number of bytes that were needed.
+    pub needed: usize,
+    /// The number of bytes that remained.
+    pub remaining: usize,
+}
+
 impl Display for InvalidData {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "the bytes did not represent a valid value")
@@ -42,6 +141,181 @@ impl Display for InvalidBool {
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidBool {}
 
+impl Display for InvalidNonZero {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "the bytes represented zero, which is not a valid value")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidNonZero {}
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "the number was out of the range of valid values")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "the number {} was out of the bounds {}..={}",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
+impl Display for InvalidStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "the bytes did not represent a valid string")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidStr {}
+
+impl Display for StrTooLong {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "the string was too long to be stored")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrTooLong {}
+
+impl Display for EncodeStrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            EncodeStrError::TooLong(e) => Display::fmt(e, f),
+            EncodeStrError::Invalid(_) => {
+                write!(f, "the string was not representable in the encoding")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeStrError {}
+
+impl Display for InvalidConst {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "the bytes did not represent the expected constant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidConst {}
+
+impl Display for ParseGuidError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "the string was not a GUID in the canonical form")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseGuidError {}
+
+impl<E: Display> Display for SliceError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SliceError::NotEnoughBytes(e) => Display::fmt(e, f),
+            SliceError::TrailingBytes { extra } => write!(f, "{} trailing bytes", extra),
+            SliceError::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Debug + Display> std::error::Error for SliceError<E> {}
+
+impl Display for NotEnoughBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "not enough bytes, {} bytes needed but {} remaining",
+            self.needed, self.remaining
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotEnoughBytes {}
+
+impl Display for NotEnoughSpace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "not enough space, {} bytes needed but {} remaining",
+            self.needed, self.remaining
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotEnoughSpace {}
+
+impl<E: Debug, V: Display> Display for ReadPackedError<E, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ReadPackedError::UnexpectedEof => {
+                write!(f, "the reader ended in the middle of a value")
+            }
+            ReadPackedError::Io(e) => write!(f, "I/O error: {:?}", e),
+            ReadPackedError::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Debug, V: Debug + Display> std::error::Error for ReadPackedError<E, V> {}
+
+impl<E: Debug, V: Display> Display for WritePackedError<E, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            WritePackedError::Io(e) => write!(f, "I/O error: {:?}", e),
+            WritePackedError::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Debug, V: Debug + Display> std::error::Error for WritePackedError<E, V> {}
+
+#[cfg(feature = "embedded-io")]
+impl<E, V> From<embedded_io::ReadExactError<E>> for ReadPackedError<E, V> {
+    fn from(e: embedded_io::ReadExactError<E>) -> Self {
+        match e {
+            embedded_io::ReadExactError::UnexpectedEof => ReadPackedError::UnexpectedEof,
+            embedded_io::ReadExactError::Other(e) => ReadPackedError::Io(e),
+        }
+    }
+}
+
+impl From<StrTooLong> for EncodeStrError {
+    fn from(e: StrTooLong) -> Self {
+        EncodeStrError::TooLong(e)
+    }
+}
+
+impl From<InvalidStr> for EncodeStrError {
+    fn from(e: InvalidStr) -> Self {
+        EncodeStrError::Invalid(e)
+    }
+}
+
+impl<E> From<NotEnoughBytes> for SliceError<E> {
+    fn from(e: NotEnoughBytes) -> Self {
+        SliceError::NotEnoughBytes(e)
+    }
+}
+
 impl From<Infallible> for InvalidData {
     fn from(_: Infallible) -> Self {
         unreachable!()
@@ -60,6 +334,42 @@ impl From<Infallible> for InvalidBool {
     }
 }
 
+impl From<Infallible> for InvalidNonZero {
+    fn from(_: Infallible) -> Self {
+        unreachable!()
+    }
+}
+
+impl From<Infallible> for OutOfRange {
+    fn from(_: Infallible) -> Self {
+        unreachable!()
+    }
+}
+
+impl From<Infallible> for OutOfBounds {
+    fn from(_: Infallible) -> Self {
+        unreachable!()
+    }
+}
+
+impl From<Infallible> for InvalidStr {
+    fn from(_: Infallible) -> Self {
+        unreachable!()
+    }
+}
+
+impl From<Infallible> for StrTooLong {
+    fn from(_: Infallible) -> Self {
+        unreachable!()
+    }
+}
+
+impl From<Infallible> for InvalidConst {
+    fn from(_: Infallible) -> Self {
+        unreachable!()
+    }
+}
+
 impl From<InvalidChar> for InvalidData {
     fn from(_: InvalidChar) -> Self {
         InvalidData
@@ -71,3 +381,33 @@ impl From<InvalidBool> for InvalidData {
         InvalidData
     }
 }
+
+impl From<InvalidNonZero> for InvalidData {
+    fn from(_: InvalidNonZero) -> Self {
+        InvalidData
+    }
+}
+
+impl From<OutOfRange> for InvalidData {
+    fn from(_: OutOfRange) -> Self {
+        InvalidData
+    }
+}
+
+impl From<OutOfBounds> for InvalidData {
+    fn from(_: OutOfBounds) -> Self {
+        InvalidData
+    }
+}
+
+impl From<InvalidStr> for InvalidData {
+    fn from(_: InvalidStr) -> Self {
+        InvalidData
+    }
+}
+
+impl From<InvalidConst> for InvalidData {
+    fn from(_: InvalidConst) -> Self {
+        InvalidData
+    }
+}
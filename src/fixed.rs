@@ -0,0 +1,228 @@
+use crate::{FromBytes, ToBytes};
+use core::{fmt, ops};
+
+/// A fixed-point number with `FRAC` fractional bits, stored in the integer type `T`.
+///
+/// The represented value is `bits / 2^FRAC`, where `bits` is the inner integer. For example,
+/// `Fixed<i32, 16>` is the Q16.16 format. It is packed in the same way as the inner integer.
+///
+/// Arithmetic follows the semantics of the inner integer, in particular it panics on overflow
+/// in debug mode and wraps around in release mode. Multiplication and division are computed in
+/// an integer of double width, whose result is truncated to the inner integer.
+///
+/// ```
+/// use packbytes::{Fixed, FromBytes};
+///
+/// let x = Fixed::<i16, 8>::from_le_bytes([0x80, 0x01]);
+/// assert_eq!(x.to_f32(), 1.5);
+/// assert_eq!((x * x).to_f32(), 2.25);
+/// assert_eq!(Fixed::<i16, 8>::from_f32(-0.25).to_bits(), -0x40);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Fixed<T, const FRAC: u32>(T);
+
+/// A fixed-point number in the Q15 format, with 15 fractional bits stored in `i16`.
+pub type Q15 = Fixed<i16, 15>;
+/// A fixed-point number in the Q31 (also called Q1.31) format, with 31 fractional bits
+/// stored in `i32`.
+pub type Q31 = Fixed<i32, 31>;
+/// A fixed-point number in the Q16.16 format, with 16 fractional bits stored in `i32`.
+pub type Q16_16 = Fixed<i32, 16>;
+
+impl<T, const FRAC: u32> Fixed<T, FRAC> {
+    /// Create a fixed-point number from its underlying integer representation.
+    #[inline]
+    pub const fn from_bits(bits: T) -> Self {
+        Fixed(bits)
+    }
+}
+
+impl<T: Copy, const FRAC: u32> Fixed<T, FRAC> {
+    /// Return the underlying integer representation of this number.
+    #[inline]
+    pub const fn to_bits(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! fixed_impls {
+    ($($int:ident($wide:ident)),* $(,)?) => {
+        $(
+            impl<const FRAC: u32> Fixed<$int, FRAC> {
+                /// The factor by which the inner integer is divided.
+                const SCALE: f64 = (1u128 << FRAC) as f64;
+
+                /// Create a fixed-point number from `f32`, rounding to the nearest representable
+                /// value and saturating on overflow.
+                #[inline]
+                pub fn from_f32(val: f32) -> Self {
+                    Self::from_f64(val as f64)
+                }
+
+                /// Create a fixed-point number from `f64`, rounding to the nearest representable
+                /// value and saturating on overflow.
+                #[inline]
+                pub fn from_f64(val: f64) -> Self {
+                    let scaled = val * Self::SCALE;
+                    // The cast truncates towards zero, so moving by a half away from it rounds
+                    // (`f64::round` is not available without `std`).
+                    let rounded = if scaled >= 0.0 { scaled + 0.5 } else { scaled - 0.5 };
+                    Fixed(rounded as $int)
+                }
+
+                /// Convert this number to `f32`.
+                #[inline]
+                pub fn to_f32(self) -> f32 {
+                    self.to_f64() as f32
+                }
+
+                /// Convert this number to `f64`.
+                #[inline]
+                pub fn to_f64(self) -> f64 {
+                    self.0 as f64 / Self::SCALE
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Display for Fixed<$int, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.to_f64(), f)
+                }
+            }
+
+            impl<const FRAC: u32> ops::Add for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    Fixed(self.0 + rhs.0)
+                }
+            }
+
+            impl<const FRAC: u32> ops::Sub for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    Fixed(self.0 - rhs.0)
+                }
+            }
+
+            impl<const FRAC: u32> ops::Mul for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    let product = (self.0 as $wide * rhs.0 as $wide) >> FRAC;
+                    debug_assert!(
+                        <$int>::try_from(product).is_ok(),
+                        "attempt to multiply with overflow"
+                    );
+                    Fixed(product as $int)
+                }
+            }
+
+            impl<const FRAC: u32> ops::Div for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: Self) -> Self {
+                    let quotient = ((self.0 as $wide) << FRAC) / rhs.0 as $wide;
+                    debug_assert!(
+                        <$int>::try_from(quotient).is_ok(),
+                        "attempt to divide with overflow"
+                    );
+                    Fixed(quotient as $int)
+                }
+            }
+
+            impl<const FRAC: u32> ops::AddAssign for Fixed<$int, FRAC> {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<const FRAC: u32> ops::SubAssign for Fixed<$int, FRAC> {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<const FRAC: u32> ops::MulAssign for Fixed<$int, FRAC> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl<const FRAC: u32> ops::DivAssign for Fixed<$int, FRAC> {
+                #[inline]
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+        )*
+    };
+}
+
+fixed_impls!(
+    u8(u16),
+    i8(i16),
+    u16(u32),
+    i16(i32),
+    u32(u64),
+    i32(i64),
+    u64(u128),
+    i64(i128),
+);
+
+macro_rules! fixed_neg_impls {
+    ($($int:ident),*) => {
+        $(
+            impl<const FRAC: u32> ops::Neg for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    Fixed(-self.0)
+                }
+            }
+        )*
+    };
+}
+
+fixed_neg_impls!(i8, i16, i32, i64);
+
+impl<T: FromBytes, const FRAC: u32> FromBytes for Fixed<T, FRAC> {
+    type Bytes = T::Bytes;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Fixed(T::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Fixed(T::from_be_bytes(bytes))
+    }
+}
+
+impl<T: ToBytes, const FRAC: u32> ToBytes for Fixed<T, FRAC> {
+    type Bytes = T::Bytes;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
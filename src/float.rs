@@ -0,0 +1,284 @@
+use crate::{FromBytes, ToBytes};
+use core::cmp::Ordering;
+use core::fmt;
+
+/// A 16-bit floating point number in the IEEE 754 binary16 (half precision) format.
+///
+/// It is meant for storage only: it can be converted to `f32` and `f64` without loss of precision,
+/// which should be used for computations.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct F16(u16);
+
+impl F16 {
+    /// Positive zero.
+    pub const ZERO: Self = F16(0);
+    /// Largest finite value.
+    pub const MAX: Self = F16(0x7bff);
+    /// Smallest finite value.
+    pub const MIN: Self = F16(0xfbff);
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: Self = F16(0x0400);
+    /// Positive infinity.
+    pub const INFINITY: Self = F16(0x7c00);
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = F16(0xfc00);
+    /// Not a number.
+    pub const NAN: Self = F16(0x7e00);
+
+    /// Create a number from its bit representation.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        F16(bits)
+    }
+
+    /// Return the bit representation of this number.
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Convert `f32` to the nearest representable number, rounding ties to even.
+    ///
+    /// Values too large in magnitude become infinite, and too small become zero.
+    pub const fn from_f32(val: f32) -> Self {
+        let bits = val.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let man = bits & 0x7f_ffff;
+
+        if exp == 0xff {
+            // Infinity or NaN, keeping the highest bits of the payload and the NaN quiet.
+            let nan = if man != 0 {
+                0x200 | (man >> 13) as u16
+            } else {
+                0
+            };
+            return F16(sign | 0x7c00 | nan);
+        }
+
+        let exp = exp - 127 + 15;
+        if exp >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+        if exp <= 0 {
+            // A subnormal number or zero.
+            if exp < -10 {
+                return F16(sign);
+            }
+            let man = man | 0x80_0000;
+            let shift = (14 - exp) as u32;
+            let half = 1 << (shift - 1);
+            let rem = man & ((1 << shift) - 1);
+            let mut man = man >> shift;
+            if rem > half || (rem == half && man & 1 == 1) {
+                // Might carry into the exponent, which is correct.
+                man += 1;
+            }
+            return F16(sign | man as u16);
+        }
+
+        let mut half = ((exp as u32) << 10) | (man >> 13);
+        let rem = man & 0x1fff;
+        if rem > 0x1000 || (rem == 0x1000 && half & 1 == 1) {
+            // Might carry into the exponent, even up to infinity, which is correct.
+            half += 1;
+        }
+        F16(sign | half as u16)
+    }
+
+    /// Convert `f64` to the nearest representable number.
+    ///
+    /// The conversion goes through `f32`, so in rare cases the result can be rounded twice.
+    #[inline]
+    pub fn from_f64(val: f64) -> Self {
+        Self::from_f32(val as f32)
+    }
+
+    /// Convert this number to `f32` losslessly.
+    pub const fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exp = ((self.0 >> 10) & 0x1f) as u32;
+        let man = (self.0 & 0x3ff) as u32;
+
+        let bits = if exp == 0x1f {
+            sign | 0x7f80_0000 | (man << 13)
+        } else if exp == 0 {
+            if man == 0 {
+                sign
+            } else {
+                // A subnormal number, which is normal in `f32`.
+                let shift = man.leading_zeros() - 21;
+                let man = (man << shift) & 0x3ff;
+                sign | ((127 - 15 + 1 - shift) << 23) | (man << 13)
+            }
+        } else {
+            sign | ((exp + 127 - 15) << 23) | (man << 13)
+        };
+        f32::from_bits(bits)
+    }
+
+    /// Convert this number to `f64` losslessly.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Return `true` if this value is NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7c00
+    }
+}
+
+/// A 16-bit floating point number in the bfloat16 format, i.e. `f32` with the lower 16 bits
+/// of the mantissa cut off.
+///
+/// It is meant for storage only: it can be converted to `f32` and `f64` without loss of precision,
+/// which should be used for computations.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct BF16(u16);
+
+impl BF16 {
+    /// Positive zero.
+    pub const ZERO: Self = BF16(0);
+    /// Largest finite value.
+    pub const MAX: Self = BF16(0x7f7f);
+    /// Smallest finite value.
+    pub const MIN: Self = BF16(0xff7f);
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: Self = BF16(0x0080);
+    /// Positive infinity.
+    pub const INFINITY: Self = BF16(0x7f80);
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = BF16(0xff80);
+    /// Not a number.
+    pub const NAN: Self = BF16(0x7fc0);
+
+    /// Create a number from its bit representation.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        BF16(bits)
+    }
+
+    /// Return the bit representation of this number.
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Convert `f32` to the nearest representable number, rounding ties to even.
+    pub const fn from_f32(val: f32) -> Self {
+        let bits = val.to_bits();
+        if val.is_nan() {
+            // Keep the highest bits of the payload and make sure the NaN stays quiet.
+            return BF16((bits >> 16) as u16 | 0x40);
+        }
+        let round = 0x7fff + ((bits >> 16) & 1);
+        BF16((bits.wrapping_add(round) >> 16) as u16)
+    }
+
+    /// Convert `f64` to the nearest representable number.
+    ///
+    /// The conversion goes through `f32`, so in rare cases the result can be rounded twice.
+    #[inline]
+    pub fn from_f64(val: f64) -> Self {
+        Self::from_f32(val as f32)
+    }
+
+    /// Convert this number to `f32` losslessly.
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+
+    /// Convert this number to `f64` losslessly.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Return `true` if this value is NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7f80
+    }
+}
+
+macro_rules! half_float_impls {
+    ($($float:ident),*) => {
+        $(
+            impl From<$float> for f32 {
+                #[inline]
+                fn from(val: $float) -> Self {
+                    val.to_f32()
+                }
+            }
+
+            impl From<$float> for f64 {
+                #[inline]
+                fn from(val: $float) -> Self {
+                    val.to_f64()
+                }
+            }
+
+            /// Compares the values as floating point numbers, e.g. NaN is not equal to itself.
+            impl PartialEq for $float {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.to_f32() == other.to_f32()
+                }
+            }
+
+            impl PartialOrd for $float {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    self.to_f32().partial_cmp(&other.to_f32())
+                }
+            }
+
+            impl fmt::Debug for $float {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.to_f32(), f)
+                }
+            }
+
+            impl fmt::Display for $float {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.to_f32(), f)
+                }
+            }
+
+            impl FromBytes for $float {
+                type Bytes = [u8; 2];
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    $float(u16::from_le_bytes(bytes))
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    $float(u16::from_be_bytes(bytes))
+                }
+            }
+
+            impl ToBytes for $float {
+                type Bytes = [u8; 2];
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    self.0.to_le_bytes()
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    self.0.to_be_bytes()
+                }
+            }
+        )*
+    };
+}
+
+half_float_impls!(F16, BF16);
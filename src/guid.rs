@@ -0,0 +1,277 @@
+use crate::error::ParseGuidError;
+use crate::{FromBytes, ToBytes};
+use core::fmt;
+use core::str::FromStr;
+
+/// A globally unique identifier (GUID), also known as UUID.
+///
+/// There are two common layouts of GUIDs in bytes, which correspond to the two byte orders:
+/// - the little endian order gives the Microsoft layout, used by GPT partition tables, UEFI, COM
+///   and many Windows formats: the first three groups are stored in the little endian order and
+///   the last eight bytes as they are,
+/// - the big endian order gives the layout of RFC 4122 UUIDs, where all the bytes are stored in
+///   the order in which they are written.
+///
+/// As the layout then depends on the byte order of the containing struct, a GUID in a big endian
+/// format which is still stored in the Microsoft layout (or vice versa) is better wrapped in
+/// [`MsGuid`] or [`RfcUuid`], whose layout is fixed.
+///
+/// It is formatted and parsed in the canonical form `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+/// Parsing also accepts the form enclosed in braces, which is common on Windows.
+///
+/// ```
+/// use packbytes::{FromBytes, Guid, ToBytes};
+///
+/// let guid: Guid = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b".parse().unwrap();
+/// assert_eq!(guid.to_le_bytes()[..8], [0x28, 0x73, 0x2a, 0xc1, 0x1f, 0xf8, 0xd2, 0x11]);
+/// assert_eq!(guid.to_be_bytes()[..8], [0xc1, 0x2a, 0x73, 0x28, 0xf8, 0x1f, 0x11, 0xd2]);
+/// assert_eq!(Guid::from_le_bytes(guid.to_le_bytes()), guid);
+/// assert_eq!(guid.to_string(), "c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Guid(u128);
+
+impl Guid {
+    /// The nil GUID, with all the bits set to zero.
+    pub const NIL: Self = Guid(0);
+
+    /// Create a GUID from its fields: the first three groups and the last eight bytes.
+    #[inline]
+    pub const fn from_fields(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Guid(
+            (data1 as u128) << 96
+                | (data2 as u128) << 80
+                | (data3 as u128) << 64
+                | u64::from_be_bytes(data4) as u128,
+        )
+    }
+
+    /// Return the fields of the GUID: the first three groups and the last eight bytes.
+    #[inline]
+    pub const fn to_fields(self) -> (u32, u16, u16, [u8; 8]) {
+        (
+            (self.0 >> 96) as u32,
+            (self.0 >> 80) as u16,
+            (self.0 >> 64) as u16,
+            (self.0 as u64).to_be_bytes(),
+        )
+    }
+
+    /// Create a GUID from a number, whose most significant byte is the first one written.
+    #[inline]
+    pub const fn from_u128(n: u128) -> Self {
+        Guid(n)
+    }
+
+    /// Return the GUID as a number, whose most significant byte is the first one written.
+    #[inline]
+    pub const fn to_u128(self) -> u128 {
+        self.0
+    }
+
+    /// Is this the nil GUID?
+    #[inline]
+    pub const fn is_nil(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (data1, data2, data3, data4) = self.to_fields();
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-",
+            data1, data2, data3, data4[0], data4[1]
+        )?;
+        data4[2..].iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Guid {
+    type Err = ParseGuidError;
+
+    fn from_str(s: &str) -> Result<Self, ParseGuidError> {
+        let s = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .unwrap_or(s)
+            .as_bytes();
+        if s.len() != 36 {
+            return Err(ParseGuidError);
+        }
+        let mut n = 0;
+        for (i, &c) in s.iter().enumerate() {
+            if matches!(i, 8 | 13 | 18 | 23) {
+                if c != b'-' {
+                    return Err(ParseGuidError);
+                }
+                continue;
+            }
+            let digit = (c as char).to_digit(16).ok_or(ParseGuidError)?;
+            n = n << 4 | digit as u128;
+        }
+        Ok(Guid(n))
+    }
+}
+
+/// The little endian order gives the Microsoft layout, the big endian order the RFC 4122 layout.
+impl FromBytes for Guid {
+    type Bytes = [u8; 16];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        let mut data4 = [0; 8];
+        data4.copy_from_slice(&bytes[8..]);
+        Self::from_fields(
+            u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            u16::from_le_bytes([bytes[4], bytes[5]]),
+            u16::from_le_bytes([bytes[6], bytes[7]]),
+            data4,
+        )
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Guid(u128::from_be_bytes(bytes))
+    }
+}
+
+/// The little endian order gives the Microsoft layout, the big endian order the RFC 4122 layout.
+impl ToBytes for Guid {
+    type Bytes = [u8; 16];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        let (data1, data2, data3, data4) = self.to_fields();
+        let mut bytes = [0; 16];
+        bytes[..4].copy_from_slice(&data1.to_le_bytes());
+        bytes[4..6].copy_from_slice(&data2.to_le_bytes());
+        bytes[6..8].copy_from_slice(&data3.to_le_bytes());
+        bytes[8..].copy_from_slice(&data4);
+        bytes
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
+
+/// Implement the traits for a wrapper of `Guid` stored in a fixed layout, given by the methods of
+/// `Guid` packing it in the corresponding byte order.
+macro_rules! fixed_layout_impls {
+    ($ty:ident, $le:literal, $from:ident, $to:ident) => {
+        impl FromBytes for $ty {
+            type Bytes = [u8; 16];
+
+            const PREFERS_LE: bool = $le;
+
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                $ty(Guid::$from(bytes))
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                $ty(Guid::$from(bytes))
+            }
+        }
+
+        impl ToBytes for $ty {
+            type Bytes = [u8; 16];
+
+            const PREFERS_LE: bool = $le;
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                self.0.$to()
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                self.0.$to()
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = ParseGuidError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, ParseGuidError> {
+                s.parse().map($ty)
+            }
+        }
+
+        impl From<Guid> for $ty {
+            #[inline]
+            fn from(guid: Guid) -> Self {
+                $ty(guid)
+            }
+        }
+
+        impl From<$ty> for Guid {
+            #[inline]
+            fn from(guid: $ty) -> Self {
+                guid.0
+            }
+        }
+    };
+}
+
+/// A GUID always stored in the Microsoft layout, regardless of the byte order it's packed in.
+///
+/// ```
+/// use packbytes::{Guid, MsGuid, ToBytes};
+///
+/// let guid: MsGuid = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b".parse().unwrap();
+/// assert_eq!(guid.to_be_bytes(), Guid::from(guid).to_le_bytes());
+/// assert_eq!(guid.to_be_bytes()[..4], [0x28, 0x73, 0x2a, 0xc1]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MsGuid(pub Guid);
+
+fixed_layout_impls!(MsGuid, true, from_le_bytes, to_le_bytes);
+
+/// A GUID always stored in the layout of RFC 4122 UUIDs, regardless of the byte order it's
+/// packed in.
+///
+/// ```
+/// use packbytes::{Guid, RfcUuid, ToBytes};
+///
+/// let uuid: RfcUuid = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b".parse().unwrap();
+/// assert_eq!(uuid.to_le_bytes(), Guid::from(uuid).to_be_bytes());
+/// assert_eq!(uuid.to_le_bytes()[..4], [0xc1, 0x2a, 0x73, 0x28]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RfcUuid(pub Guid);
+
+fixed_layout_impls!(RfcUuid, false, from_be_bytes, to_be_bytes);
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Guid {
+    #[inline]
+    fn from(uuid: uuid::Uuid) -> Self {
+        Guid(uuid.as_u128())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<Guid> for uuid::Uuid {
+    #[inline]
+    fn from(guid: Guid) -> Self {
+        uuid::Uuid::from_u128(guid.0)
+    }
+}
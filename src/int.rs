@@ -0,0 +1,171 @@
+use crate::error::OutOfRange;
+use crate::{FromBytes, ToBytes};
+use core::fmt;
+
+/// Define an integer type of an odd width, stored in a wider native integer.
+macro_rules! odd_int {
+    (
+        $(#[$attr:meta])*
+        $name:ident($inner:ident), $bits:literal bits, $bytes:literal bytes,
+        from [$($from:ident),*], into [$($into:ident),*]
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($inner);
+
+        impl $name {
+            /// The size of this integer type in bits.
+            pub const BITS: u32 = $bits;
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = Self::new_wrapping($inner::MIN >> Self::SHIFT);
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self::new_wrapping($inner::MAX >> Self::SHIFT);
+
+            /// The number of unused high bits of the inner integer.
+            const SHIFT: u32 = $inner::BITS - $bits;
+
+            /// Create a value from a native integer, returning `None` if it is out of range.
+            #[inline]
+            pub const fn new(n: $inner) -> Option<Self> {
+                let val = Self::new_wrapping(n);
+                if val.0 == n {
+                    Some(val)
+                } else {
+                    None
+                }
+            }
+
+            /// Create a value from the lowest bits of a native integer, discarding the rest.
+            #[inline]
+            pub const fn new_wrapping(n: $inner) -> Self {
+                $name((n << Self::SHIFT) >> Self::SHIFT)
+            }
+
+            /// Return the value as a native integer.
+            #[inline]
+            pub const fn get(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl TryFrom<$inner> for $name {
+            type Error = OutOfRange;
+
+            #[inline]
+            fn try_from(n: $inner) -> Result<Self, OutOfRange> {
+                Self::new(n).ok_or(OutOfRange)
+            }
+        }
+
+        $(
+            impl From<$from> for $name {
+                #[inline]
+                fn from(n: $from) -> Self {
+                    $name(n.into())
+                }
+            }
+        )*
+
+        impl From<$name> for $inner {
+            #[inline]
+            fn from(n: $name) -> Self {
+                n.0
+            }
+        }
+
+        $(
+            impl From<$name> for $into {
+                #[inline]
+                fn from(n: $name) -> Self {
+                    n.0.into()
+                }
+            }
+        )*
+
+        impl FromBytes for $name {
+            type Bytes = [u8; $bytes];
+
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                let mut inner = [0; $inner::BITS as usize / 8];
+                inner[..$bytes].copy_from_slice(&bytes);
+                Self::new_wrapping($inner::from_le_bytes(inner))
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                let mut inner = [0; $inner::BITS as usize / 8];
+                inner[Self::SHIFT as usize / 8..].copy_from_slice(&bytes);
+                Self::new_wrapping($inner::from_be_bytes(inner))
+            }
+        }
+
+        impl ToBytes for $name {
+            type Bytes = [u8; $bytes];
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                self.0.to_le_bytes()[..$bytes].try_into().unwrap()
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                self.0.to_be_bytes()[Self::SHIFT as usize / 8..].try_into().unwrap()
+            }
+        }
+    };
+}
+
+odd_int!(
+    /// An unsigned 24-bit integer, packed into 3 bytes.
+    U24(u32), 24 bits, 3 bytes,
+    from [u8, u16], into [u64, i64, u128, i128]
+);
+
+odd_int!(
+    /// A signed 24-bit integer, packed into 3 bytes.
+    I24(i32), 24 bits, 3 bytes,
+    from [u8, i8, u16, i16], into [i64, i128]
+);
+
+odd_int!(
+    /// An unsigned 40-bit integer, packed into 5 bytes.
+    U40(u64), 40 bits, 5 bytes,
+    from [u8, u16, u32], into [u128, i128]
+);
+
+odd_int!(
+    /// A signed 40-bit integer, packed into 5 bytes.
+    I40(i64), 40 bits, 5 bytes,
+    from [u8, i8, u16, i16, u32, i32], into [i128]
+);
+
+odd_int!(
+    /// An unsigned 48-bit integer, packed into 6 bytes.
+    U48(u64), 48 bits, 6 bytes,
+    from [u8, u16, u32], into [u128, i128]
+);
+
+odd_int!(
+    /// A signed 48-bit integer, packed into 6 bytes.
+    I48(i64), 48 bits, 6 bytes,
+    from [u8, i8, u16, i16, u32, i32], into [i128]
+);
+
+odd_int!(
+    /// An unsigned 56-bit integer, packed into 7 bytes.
+    U56(u64), 56 bits, 7 bytes,
+    from [u8, u16, u32], into [u128, i128]
+);
+
+odd_int!(
+    /// A signed 56-bit integer, packed into 7 bytes.
+    I56(i64), 56 bits, 7 bytes,
+    from [u8, i8, u16, i16, u32, i32], into [i128]
+);
@@ -0,0 +1,293 @@
+use crate::error::NotEnoughBytes;
+use crate::{ByteArray, FromBytes, ToBytes, TryFromBytes, TryToBytes};
+use std::error::Error;
+use std::io;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// Read a byte array of the type `B` from a reader.
+fn read_bytes<B: ByteArray, R: io::Read + ?Sized>(reader: &mut R) -> io::Result<B> {
+    let mut bytes = B::zeroed();
+    reader.read_exact(bytes.as_mut())?;
+    Ok(bytes)
+}
+
+/// Convert an error of packing or unpacking to an I/O error of the kind `InvalidData`.
+pub(crate) fn invalid_data<E: Into<Box<dyn Error + Send + Sync>>>(e: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+/// Extension methods for reading packed values, implemented for every reader.
+///
+/// ```
+/// use packbytes::ReadPackedExt;
+///
+/// let mut reader: &[u8] = &[1, 0, 0, 2, 3];
+/// assert_eq!(reader.read_packed::<u16>().unwrap(), 1);
+/// assert_eq!(reader.read_packed_be::<u16>().unwrap(), 2);
+/// assert!(reader.try_read_packed::<bool>().is_err());
+/// ```
+pub trait ReadPackedExt: io::Read {
+    /// Read a value in its preferred byte order.
+    #[inline]
+    fn read_packed<T: FromBytes>(&mut self) -> io::Result<T> {
+        read_bytes(self).map(T::from_bytes)
+    }
+
+    /// Read a value in the little endian byte order.
+    #[inline]
+    fn read_packed_le<T: FromBytes>(&mut self) -> io::Result<T> {
+        read_bytes(self).map(T::from_le_bytes)
+    }
+
+    /// Read a value in the big endian byte order.
+    #[inline]
+    fn read_packed_be<T: FromBytes>(&mut self) -> io::Result<T> {
+        read_bytes(self).map(T::from_be_bytes)
+    }
+
+    /// Try to read a value in its preferred byte order.
+    ///
+    /// If the bytes don't represent a valid value, an error of the kind `InvalidData`
+    /// is returned, which wraps the error of the conversion.
+    #[inline]
+    fn try_read_packed<T>(&mut self) -> io::Result<T>
+    where
+        T: TryFromBytes,
+        T::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        T::try_from_bytes(read_bytes(self)?).map_err(invalid_data)
+    }
+
+    /// Try to read a value in the little endian byte order.
+    ///
+    /// If the bytes don't represent a valid value, an error of the kind `InvalidData`
+    /// is returned, which wraps the error of the conversion.
+    #[inline]
+    fn try_read_packed_le<T>(&mut self) -> io::Result<T>
+    where
+        T: TryFromBytes,
+        T::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        T::try_from_le_bytes(read_bytes(self)?).map_err(invalid_data)
+    }
+
+    /// Try to read a value in the big endian byte order.
+    ///
+    /// If the bytes don't represent a valid value, an error of the kind `InvalidData`
+    /// is returned, which wraps the error of the conversion.
+    #[inline]
+    fn try_read_packed_be<T>(&mut self) -> io::Result<T>
+    where
+        T: TryFromBytes,
+        T::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        T::try_from_be_bytes(read_bytes(self)?).map_err(invalid_data)
+    }
+}
+
+impl<R: io::Read + ?Sized> ReadPackedExt for R {}
+
+/// Extension methods for writing packed values, implemented for every writer.
+///
+/// ```
+/// use packbytes::WritePackedExt;
+///
+/// let mut writer = Vec::new();
+/// writer.write_packed(1u16).unwrap();
+/// writer.write_packed_be(2u16).unwrap();
+/// assert_eq!(writer, [1, 0, 0, 2]);
+/// ```
+pub trait WritePackedExt: io::Write {
+    /// Write a value in its preferred byte order.
+    #[inline]
+    fn write_packed<T: ToBytes>(&mut self, value: T) -> io::Result<()> {
+        self.write_all(value.to_bytes().as_ref())
+    }
+
+    /// Write a value in the little endian byte order.
+    #[inline]
+    fn write_packed_le<T: ToBytes>(&mut self, value: T) -> io::Result<()> {
+        self.write_all(value.to_le_bytes().as_ref())
+    }
+
+    /// Write a value in the big endian byte order.
+    #[inline]
+    fn write_packed_be<T: ToBytes>(&mut self, value: T) -> io::Result<()> {
+        self.write_all(value.to_be_bytes().as_ref())
+    }
+
+    /// Try to write a value in its preferred byte order.
+    ///
+    /// If the value can't be packed, an error of the kind `InvalidData` is returned,
+    /// which wraps the error of the conversion.
+    #[inline]
+    fn try_write_packed<T>(&mut self, value: T) -> io::Result<()>
+    where
+        T: TryToBytes,
+        T::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.write_all(value.try_to_bytes().map_err(invalid_data)?.as_ref())
+    }
+
+    /// Try to write a value in the little endian byte order.
+    ///
+    /// If the value can't be packed, an error of the kind `InvalidData` is returned,
+    /// which wraps the error of the conversion.
+    #[inline]
+    fn try_write_packed_le<T>(&mut self, value: T) -> io::Result<()>
+    where
+        T: TryToBytes,
+        T::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.write_all(value.try_to_le_bytes().map_err(invalid_data)?.as_ref())
+    }
+
+    /// Try to write a value in the big endian byte order.
+    ///
+    /// If the value can't be packed, an error of the kind `InvalidData` is returned,
+    /// which wraps the error of the conversion.
+    #[inline]
+    fn try_write_packed_be<T>(&mut self, value: T) -> io::Result<()>
+    where
+        T: TryToBytes,
+        T::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.write_all(value.try_to_be_bytes().map_err(invalid_data)?.as_ref())
+    }
+}
+
+impl<W: io::Write + ?Sized> WritePackedExt for W {}
+
+/// An iterator reading packed values of the type `T` from a reader one after another, created by
+/// the `iter_packed` and `try_iter_packed` methods.
+///
+/// The iteration ends when the reader is at its end right after a value. If it ends in the middle
+/// of a value, an error of the kind `UnexpectedEof` is returned, which wraps [`NotEnoughBytes`].
+/// For types implementing `TryFromBytes`, an invalid value results in an error of the kind
+/// `InvalidData`. After an error, the iteration ends. For zero-sized types, the iterator yields
+/// nothing, like [`DecodeSlice`](crate::DecodeSlice).
+///
+/// By default, only the bytes of the current value are read at once, so the reader can be used
+/// again after the iteration. Reading ahead in larger chunks can be enabled by `read_ahead`.
+///
+/// ```
+/// use packbytes::FromBytes;
+/// use std::io::ErrorKind;
+///
+/// let values: Vec<_> = u16::iter_packed(&[1, 0, 2, 0][..]).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(values, [1, 2]);
+///
+/// let mut iter = u16::iter_packed(&[1, 0, 2][..]);
+/// assert_eq!(iter.next().unwrap().unwrap(), 1);
+/// assert_eq!(iter.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+/// assert!(iter.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct PackedIter<T, R> {
+    reader: R,
+    buf: Vec<u8>,
+    start: usize,
+    end: usize,
+    done: bool,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<T: TryFromBytes, R: io::Read> PackedIter<T, R> {
+    /// Create an iterator reading the values from the reader.
+    pub(crate) fn new(reader: R) -> Self {
+        PackedIter {
+            reader,
+            buf: vec![0; T::Bytes::SIZE],
+            start: 0,
+            end: 0,
+            done: false,
+            ty: PhantomData,
+        }
+    }
+
+    /// Read up to `count` values at once from the reader and keep them in a buffer.
+    ///
+    /// This reduces the number of reads from unbuffered readers, but the bytes read ahead
+    /// are lost when the iterator is dropped.
+    pub fn read_ahead(mut self, count: usize) -> Self {
+        let len = count.max(1) * T::Bytes::SIZE;
+        self.buf.copy_within(self.start..self.end, 0);
+        self.end -= self.start;
+        self.start = 0;
+        self.buf.resize(len.max(self.end), 0);
+        self
+    }
+
+    /// Return the bytes of the next value, or `None` at the end of the reader.
+    fn next_bytes(&mut self) -> io::Result<Option<T::Bytes>> {
+        let size = T::Bytes::SIZE;
+        if size == 0 {
+            return Ok(None);
+        }
+        if self.end - self.start < size {
+            self.buf.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+            while self.end < size {
+                match self.reader.read(&mut self.buf[self.end..]) {
+                    Ok(0) => break,
+                    Ok(n) => self.end += n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            if self.end == 0 {
+                return Ok(None);
+            }
+            if self.end < size {
+                let remaining = self.end;
+                self.end = 0;
+                let e = NotEnoughBytes {
+                    needed: size,
+                    remaining,
+                };
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, e));
+            }
+        }
+        let mut bytes = T::Bytes::zeroed();
+        bytes
+            .as_mut()
+            .copy_from_slice(&self.buf[self.start..self.start + size]);
+        self.start += size;
+        Ok(Some(bytes))
+    }
+}
+
+impl<T, R> Iterator for PackedIter<T, R>
+where
+    T: TryFromBytes,
+    T::Error: Into<Box<dyn Error + Send + Sync>>,
+    R: io::Read,
+{
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = match self.next_bytes() {
+            Ok(Some(bytes)) => T::try_from_bytes(bytes).map_err(invalid_data),
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => Err(e),
+        };
+        self.done = result.is_err();
+        Some(result)
+    }
+}
+
+impl<T, R> FusedIterator for PackedIter<T, R>
+where
+    T: TryFromBytes,
+    T::Error: Into<Box<dyn Error + Send + Sync>>,
+    R: io::Read,
+{
+}
@@ -2,15 +2,74 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
+mod array;
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod async_io;
+mod bounded;
+mod buf;
+mod bulk;
+mod constant;
+mod decoder;
+mod embedded;
+mod encoder;
 /// Errors signaling bytes that don't represent valid data.
 pub mod error;
+/// Extension traits for the I/O traits and buffers of other crates, enabled by their features.
+#[cfg(any(
+    feature = "bytes",
+    feature = "embedded-io",
+    feature = "futures-io",
+    feature = "tokio"
+))]
+pub mod ext {
+    #[cfg(feature = "futures-io")]
+    pub use crate::async_io::futures;
+    #[cfg(feature = "tokio")]
+    pub use crate::async_io::tokio;
+    #[cfg(feature = "bytes")]
+    pub use crate::buf::bytes;
+    #[cfg(feature = "embedded-io")]
+    pub use crate::embedded::embedded_io;
+    #[cfg(feature = "embedded-io-async")]
+    pub use crate::embedded::embedded_io_async;
+}
+mod fixed;
+mod float;
+mod guid;
+mod int;
+#[cfg(feature = "std")]
+mod io;
+mod net;
 mod primitives;
+mod string;
+mod swap;
+mod time;
+mod tuple;
+
+pub use array::{ArrayBytes, PackedArray, TryPackedArray};
+pub use bounded::Bounded;
+pub use bulk::DecodeSlice;
+pub use constant::Const;
+pub use decoder::Decoder;
+pub use encoder::{Encoder, Reserved};
+pub use fixed::{Fixed, Q15, Q16_16, Q31};
+pub use float::{BF16, F16};
+pub use guid::{Guid, MsGuid, RfcUuid};
+pub use int::{I24, I40, I48, I56, U24, U40, U48, U56};
+#[cfg(feature = "std")]
+pub use io::{PackedIter, ReadPackedExt, WritePackedExt};
+pub use string::{
+    AsciiStr, AsciiStrSpacePadded, FixedStr, FixedStrSpacePadded, Latin1Str, Utf16Str,
+};
+pub use time::{DosDateTime, FileTime, GpsTime, NtpTimestamp, UnixTime32};
+pub use tuple::{ConcatBytes, TryTuple};
 
 #[cfg(feature = "packbytes-derive")]
-pub use packbytes_derive::{FromBytes, ToBytes, TryFromBytes};
+pub use packbytes_derive::{FromBytes, PackedFlags, ToBytes, TryFromBytes, TryToBytes};
 
 use core::convert::Infallible;
 use core::ops;
+use error::{NotEnoughBytes, NotEnoughSpace, SliceError};
 
 /// A private module to disallow implementing ByteArray on other types than byte arrays.
 mod private {
@@ -22,6 +81,8 @@ mod private {
 /// A helper trait that is implemented only for byte arrays.
 ///
 /// It is necessary since associated constants cannot be used in function signatures so far.
+/// Apart from the arrays `[u8; N]`, it is implemented for [`ArrayBytes`] and [`ConcatBytes`],
+/// which are laid out in memory just like them.
 pub trait ByteArray:
     private::ByteArray
     + ops::IndexMut<usize, Output = u8>
@@ -85,6 +146,51 @@ pub trait FromBytes: Sized {
         }
     }
 
+    /// Create a value of this type from the beginning of a slice in the preferred byte order
+    /// (set in the associated constant `PREFERS_LE`), returning it with the rest of the slice.
+    ///
+    /// Fails if the slice is shorter than the byte representation.
+    ///
+    /// ```
+    /// use packbytes::{error::NotEnoughBytes, FromBytes};
+    ///
+    /// let input = [1, 0, 2, 0, 0, 0, 3];
+    /// let (a, rest) = u16::from_slice_prefix(&input).unwrap();
+    /// let (b, rest) = u32::from_slice_prefix(rest).unwrap();
+    /// assert_eq!((a, b, rest), (1, 2, &[3][..]));
+    /// assert_eq!(
+    ///     u16::from_slice_prefix(rest),
+    ///     Err(NotEnoughBytes { needed: 2, remaining: 1 })
+    /// );
+    /// ```
+    #[inline]
+    fn from_slice_prefix(slice: &[u8]) -> Result<(Self, &[u8]), NotEnoughBytes> {
+        let needed = Self::Bytes::SIZE;
+        if slice.len() < needed {
+            return Err(NotEnoughBytes {
+                needed,
+                remaining: slice.len(),
+            });
+        }
+        let (head, rest) = slice.split_at(needed);
+        let mut bytes = Self::Bytes::zeroed();
+        bytes.as_mut().copy_from_slice(head);
+        Ok((Self::from_bytes(bytes), rest))
+    }
+
+    /// Create a value of this type from a slice in the preferred byte order (set in the associated
+    /// constant `PREFERS_LE`).
+    ///
+    /// Fails if the length of the slice is not exactly the size of the byte representation.
+    #[inline]
+    fn from_slice(slice: &[u8]) -> Result<Self, SliceError<Infallible>> {
+        let (val, rest) = Self::from_slice_prefix(slice)?;
+        if !rest.is_empty() {
+            return Err(SliceError::TrailingBytes { extra: rest.len() });
+        }
+        Ok(val)
+    }
+
     /// Read a byte representation of this type in the preferred byte order (set in the associated
     /// constant `PREFERS_LE`) and create a value of this type from it.
     #[cfg(feature = "std")]
@@ -94,6 +200,86 @@ pub trait FromBytes: Sized {
         reader.read_exact(bytes.as_mut())?;
         Ok(Self::from_bytes(bytes))
     }
+
+    /// Return an iterator reading values of this type from a reader one after another,
+    /// in the preferred byte order (set in the associated constant `PREFERS_LE`).
+    ///
+    /// See [`PackedIter`] for how the end of the reader is handled.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn iter_packed<R: std::io::Read>(reader: R) -> PackedIter<Self, R> {
+        PackedIter::new(reader)
+    }
+
+    /// Return an iterator decoding values of this type packed one after another in a slice,
+    /// in the preferred byte order (set in the associated constant `PREFERS_LE`).
+    ///
+    /// See [`DecodeSlice`] for how the end of the slice is handled.
+    #[inline]
+    fn decode_slice(bytes: &[u8]) -> DecodeSlice<'_, Self> {
+        DecodeSlice::new(bytes)
+    }
+
+    /// Decode values of this type packed one after another at the beginning of a slice in the
+    /// preferred byte order (set in the associated constant `PREFERS_LE`), filling the whole
+    /// output slice. Returns the number of bytes read.
+    ///
+    /// Fails if the input slice doesn't contain enough bytes to fill the output.
+    ///
+    /// ```
+    /// use packbytes::FromBytes;
+    ///
+    /// let mut values = [0u16; 2];
+    /// assert_eq!(u16::decode_into(&[1, 0, 2, 0, 3], &mut values), Ok(4));
+    /// assert_eq!(values, [1, 2]);
+    /// assert!(u16::decode_into(&[1, 0, 2], &mut values).is_err());
+    /// ```
+    #[inline]
+    fn decode_into(bytes: &[u8], out: &mut [Self]) -> Result<usize, NotEnoughBytes> {
+        if Self::PREFERS_LE {
+            Self::decode_le_into(bytes, out)
+        } else {
+            Self::decode_be_into(bytes, out)
+        }
+    }
+
+    /// Decode values of this type packed one after another at the beginning of a slice in the
+    /// little endian byte order, filling the whole output slice. Returns the number of bytes read.
+    ///
+    /// For primitive numbers, the bytes are copied in the native byte order and, if it differs,
+    /// the numbers are swapped afterwards in a separate pass.
+    #[inline]
+    fn decode_le_into(bytes: &[u8], out: &mut [Self]) -> Result<usize, NotEnoughBytes> {
+        bulk::decode_each(bytes, out, Self::from_le_bytes)
+    }
+
+    /// Decode values of this type packed one after another at the beginning of a slice in the
+    /// big endian byte order, filling the whole output slice. Returns the number of bytes read.
+    ///
+    /// For primitive numbers, the bytes are copied in the native byte order and, if it differs,
+    /// the numbers are swapped afterwards in a separate pass.
+    #[inline]
+    fn decode_be_into(bytes: &[u8], out: &mut [Self]) -> Result<usize, NotEnoughBytes> {
+        bulk::decode_each(bytes, out, Self::from_be_bytes)
+    }
+
+    /// Decode an array of values in the little endian byte order, used by the derive macros.
+    ///
+    /// Panics if the slice is shorter than the representation of the array.
+    #[doc(hidden)]
+    #[inline]
+    fn decode_le_array<const N: usize>(bytes: &[u8]) -> [Self; N] {
+        bulk::decode_array(bytes, Self::from_le_bytes)
+    }
+
+    /// Decode an array of values in the big endian byte order, used by the derive macros.
+    ///
+    /// Panics if the slice is shorter than the representation of the array.
+    #[doc(hidden)]
+    #[inline]
+    fn decode_be_array<const N: usize>(bytes: &[u8]) -> [Self; N] {
+        bulk::decode_array(bytes, Self::from_be_bytes)
+    }
 }
 
 /// Try to create a value from its representation as a packed stack byte array of a fixed size.
@@ -137,6 +323,60 @@ pub trait TryFromBytes: Sized {
             Self::try_from_be_bytes(bytes)
         }
     }
+
+    /// Return an iterator trying to read values of this type from a reader one after another,
+    /// in the preferred byte order (set in the associated constant `PREFERS_LE`).
+    ///
+    /// See [`PackedIter`] for how the end of the reader and invalid values are handled.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn try_iter_packed<R: std::io::Read>(reader: R) -> PackedIter<Self, R> {
+        PackedIter::new(reader)
+    }
+
+    /// Try to create a value of this type from the beginning of a slice in the preferred byte order
+    /// (set in the associated constant `PREFERS_LE`), returning it with the rest of the slice.
+    ///
+    /// ```
+    /// use packbytes::{error::{NotEnoughBytes, SliceError}, TryFromBytes};
+    ///
+    /// let input = [1, 2, 3];
+    /// let (a, rest) = bool::try_from_slice_prefix(&input[..1]).unwrap();
+    /// assert_eq!((a, rest), (true, &[][..]));
+    /// assert!(matches!(bool::try_from_slice_prefix(&input[1..]), Err(SliceError::Invalid(_))));
+    /// assert_eq!(
+    ///     u32::try_from_slice_prefix(&input),
+    ///     Err(SliceError::NotEnoughBytes(NotEnoughBytes { needed: 4, remaining: 3 }))
+    /// );
+    /// ```
+    #[inline]
+    fn try_from_slice_prefix(slice: &[u8]) -> Result<(Self, &[u8]), SliceError<Self::Error>> {
+        let needed = Self::Bytes::SIZE;
+        if slice.len() < needed {
+            return Err(SliceError::NotEnoughBytes(NotEnoughBytes {
+                needed,
+                remaining: slice.len(),
+            }));
+        }
+        let (head, rest) = slice.split_at(needed);
+        let mut bytes = Self::Bytes::zeroed();
+        bytes.as_mut().copy_from_slice(head);
+        let val = Self::try_from_bytes(bytes).map_err(SliceError::Invalid)?;
+        Ok((val, rest))
+    }
+
+    /// Try to create a value of this type from a slice in the preferred byte order (set in the
+    /// associated constant `PREFERS_LE`).
+    ///
+    /// Fails if the length of the slice is not exactly the size of the byte representation.
+    #[inline]
+    fn try_from_slice(slice: &[u8]) -> Result<Self, SliceError<Self::Error>> {
+        let (val, rest) = Self::try_from_slice_prefix(slice)?;
+        if !rest.is_empty() {
+            return Err(SliceError::TrailingBytes { extra: rest.len() });
+        }
+        Ok(val)
+    }
 }
 
 /// Pack this type into a stack byte array of a fixed size.
@@ -186,6 +426,133 @@ pub trait ToBytes: Sized {
     fn write_packed<W: std::io::Write>(self, writer: &mut W) -> std::io::Result<()> {
         writer.write_all(self.to_bytes().as_ref())
     }
+
+    /// Encode values of this type one after another at the beginning of a slice in the preferred
+    /// byte order (set in the associated constant `PREFERS_LE`). Returns the number of bytes
+    /// written.
+    ///
+    /// Fails if the output slice is too short to hold all the values.
+    ///
+    /// ```
+    /// use packbytes::ToBytes;
+    ///
+    /// let mut buf = [0; 5];
+    /// assert_eq!(u16::encode_slice(&[1, 2], &mut buf), Ok(4));
+    /// assert_eq!(buf, [1, 0, 2, 0, 0]);
+    /// assert!(u16::encode_slice(&[1, 2, 3], &mut buf).is_err());
+    /// ```
+    #[inline]
+    fn encode_slice(values: &[Self], out: &mut [u8]) -> Result<usize, NotEnoughSpace>
+    where
+        Self: Clone,
+    {
+        if Self::PREFERS_LE {
+            Self::encode_le_slice(values, out)
+        } else {
+            Self::encode_be_slice(values, out)
+        }
+    }
+
+    /// Encode values of this type one after another at the beginning of a slice in the little
+    /// endian byte order. Returns the number of bytes written.
+    ///
+    /// For primitive numbers, the values are written in the native byte order and, if it differs,
+    /// their bytes are swapped afterwards in a separate pass.
+    #[inline]
+    fn encode_le_slice(values: &[Self], out: &mut [u8]) -> Result<usize, NotEnoughSpace>
+    where
+        Self: Clone,
+    {
+        bulk::encode_each(values, out, Self::to_le_bytes)
+    }
+
+    /// Encode values of this type one after another at the beginning of a slice in the big
+    /// endian byte order. Returns the number of bytes written.
+    ///
+    /// For primitive numbers, the values are written in the native byte order and, if it differs,
+    /// their bytes are swapped afterwards in a separate pass.
+    #[inline]
+    fn encode_be_slice(values: &[Self], out: &mut [u8]) -> Result<usize, NotEnoughSpace>
+    where
+        Self: Clone,
+    {
+        bulk::encode_each(values, out, Self::to_be_bytes)
+    }
+
+    /// Write values of this type to a writer one after another in preferred byte order, set by
+    /// the associated constant `PREFERS_LE`.
+    ///
+    /// The values are encoded into a buffer first, so that the writer is called once per many
+    /// values instead of once per value.
+    #[cfg(feature = "std")]
+    fn write_packed_all<W: std::io::Write>(values: &[Self], writer: &mut W) -> std::io::Result<()>
+    where
+        Self: Clone,
+    {
+        const BUF_SIZE: usize = 8192;
+        let size = Self::Bytes::SIZE;
+        if size == 0 {
+            return Ok(());
+        }
+        if size > BUF_SIZE {
+            return values
+                .iter()
+                .try_for_each(|val| val.clone().write_packed(writer));
+        }
+        let mut buf = [0; BUF_SIZE];
+        for chunk in values.chunks(BUF_SIZE / size) {
+            let len = chunk.len() * size;
+            Self::encode_slice(chunk, &mut buf[..len]).expect("the buffer is large enough");
+            writer.write_all(&buf[..len])?;
+        }
+        Ok(())
+    }
+}
+
+/// Try to pack this type into a stack byte array of a fixed size.
+///
+/// Most times, the method `try_to_bytes` should be used, as it ensures consistency by respecting
+/// the byte order set by the `PREFERS_LE` associated constant.
+pub trait TryToBytes: Sized {
+    /// A byte array which can store a packed representation of this type.
+    type Bytes: ByteArray;
+    /// A type containing the failure of packing a value of the type into bytes.
+    type Error;
+
+    /// Is it preferred to represent this type as bytes in the little endian order?
+    const PREFERS_LE: bool = true;
+
+    /// Try to return the memory representation of this type as a byte array in little endian
+    /// byte order.
+    fn try_to_le_bytes(self) -> Result<Self::Bytes, Self::Error>;
+    /// Try to return the memory representation of this type as a byte array in big endian
+    /// byte order.
+    fn try_to_be_bytes(self) -> Result<Self::Bytes, Self::Error>;
+
+    /// Try to return the memory representation of this type as a byte array in the preferred
+    /// byte order, set in the associated constant `PREFERS_LE`.
+    #[inline]
+    fn try_to_bytes(self) -> Result<Self::Bytes, Self::Error> {
+        if Self::PREFERS_LE {
+            self.try_to_le_bytes()
+        } else {
+            self.try_to_be_bytes()
+        }
+    }
+
+    /// Try to return the memory representation of this type as a byte array in native endian
+    /// byte order.
+    ///
+    /// As the target platform’s native endianness is used, portable code likely wants to use
+    /// `try_to_le_bytes` or `try_to_be_bytes`, as appropriate instead.
+    #[inline]
+    fn try_to_ne_bytes(self) -> Result<Self::Bytes, Self::Error> {
+        if cfg!(target_endian = "little") {
+            self.try_to_le_bytes()
+        } else {
+            self.try_to_be_bytes()
+        }
+    }
 }
 
 impl<B: ByteArray, T: FromBytes<Bytes = B>> TryFromBytes for T {
@@ -194,6 +561,8 @@ impl<B: ByteArray, T: FromBytes<Bytes = B>> TryFromBytes for T {
     /// A type containing the failure of creating a value of the type from bytes.
     type Error = Infallible;
 
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
     /// Create a value of this type from its representation as a byte array in little endian.
     #[inline]
     fn try_from_le_bytes(bytes: B) -> Result<Self, Self::Error> {
@@ -207,6 +576,27 @@ impl<B: ByteArray, T: FromBytes<Bytes = B>> TryFromBytes for T {
     }
 }
 
+impl<B: ByteArray, T: ToBytes<Bytes = B>> TryToBytes for T {
+    /// A byte array which can store a packed representation of this type.
+    type Bytes = B;
+    /// A type containing the failure of packing a value of the type into bytes.
+    type Error = Infallible;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    /// Return the memory representation of this type as a byte array in little endian byte order.
+    #[inline]
+    fn try_to_le_bytes(self) -> Result<B, Self::Error> {
+        Ok(self.to_le_bytes())
+    }
+
+    /// Return the memory representation of this type as a byte array in big endian byte order.
+    #[inline]
+    fn try_to_be_bytes(self) -> Result<B, Self::Error> {
+        Ok(self.to_be_bytes())
+    }
+}
+
 impl<const N: usize> FromBytes for [u8; N] {
     type Bytes = Self;
 
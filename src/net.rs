@@ -0,0 +1,151 @@
+// Network addresses
+//
+// IP addresses are always represented in the network (big endian) order, regardless of the
+// requested byte order. Ports and the other numerical fields of socket addresses follow it.
+
+use crate::{FromBytes, ToBytes};
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+impl FromBytes for Ipv4Addr {
+    type Bytes = [u8; 4];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Ipv4Addr::from(bytes)
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Ipv4Addr::from(bytes)
+    }
+}
+
+impl ToBytes for Ipv4Addr {
+    type Bytes = [u8; 4];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.octets()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.octets()
+    }
+}
+
+impl FromBytes for Ipv6Addr {
+    type Bytes = [u8; 16];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Ipv6Addr::from(bytes)
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Ipv6Addr::from(bytes)
+    }
+}
+
+impl ToBytes for Ipv6Addr {
+    type Bytes = [u8; 16];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.octets()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.octets()
+    }
+}
+
+/// The address, followed by the port.
+impl FromBytes for SocketAddrV4 {
+    type Bytes = [u8; 6];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        let ip = Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]);
+        SocketAddrV4::new(ip, u16::from_le_bytes([bytes[4], bytes[5]]))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        let ip = Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]);
+        SocketAddrV4::new(ip, u16::from_be_bytes([bytes[4], bytes[5]]))
+    }
+}
+
+/// The address, followed by the port.
+impl ToBytes for SocketAddrV4 {
+    type Bytes = [u8; 6];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 6];
+        bytes[..4].copy_from_slice(&self.ip().octets());
+        bytes[4..].copy_from_slice(&self.port().to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 6];
+        bytes[..4].copy_from_slice(&self.ip().octets());
+        bytes[4..].copy_from_slice(&self.port().to_be_bytes());
+        bytes
+    }
+}
+
+/// The address, followed by the port, the flow information and the scope ID.
+impl FromBytes for SocketAddrV6 {
+    type Bytes = [u8; 26];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        SocketAddrV6::new(
+            Ipv6Addr::from(<[u8; 16]>::try_from(&bytes[..16]).unwrap()),
+            u16::from_le_bytes(bytes[16..18].try_into().unwrap()),
+            u32::from_le_bytes(bytes[18..22].try_into().unwrap()),
+            u32::from_le_bytes(bytes[22..].try_into().unwrap()),
+        )
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        SocketAddrV6::new(
+            Ipv6Addr::from(<[u8; 16]>::try_from(&bytes[..16]).unwrap()),
+            u16::from_be_bytes(bytes[16..18].try_into().unwrap()),
+            u32::from_be_bytes(bytes[18..22].try_into().unwrap()),
+            u32::from_be_bytes(bytes[22..].try_into().unwrap()),
+        )
+    }
+}
+
+/// The address, followed by the port, the flow information and the scope ID.
+impl ToBytes for SocketAddrV6 {
+    type Bytes = [u8; 26];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 26];
+        bytes[..16].copy_from_slice(&self.ip().octets());
+        bytes[16..18].copy_from_slice(&self.port().to_le_bytes());
+        bytes[18..22].copy_from_slice(&self.flowinfo().to_le_bytes());
+        bytes[22..].copy_from_slice(&self.scope_id().to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 26];
+        bytes[..16].copy_from_slice(&self.ip().octets());
+        bytes[16..18].copy_from_slice(&self.port().to_be_bytes());
+        bytes[18..22].copy_from_slice(&self.flowinfo().to_be_bytes());
+        bytes[22..].copy_from_slice(&self.scope_id().to_be_bytes());
+        bytes
+    }
+}
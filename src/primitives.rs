@@ -1,5 +1,10 @@
-use crate::error::{InvalidBool, InvalidChar};
+use crate::error::{InvalidBool, InvalidChar, InvalidNonZero};
+use crate::swap::{decode_methods, encode_methods};
 use crate::{FromBytes, ToBytes, TryFromBytes};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
 
 // 8-bit
 
@@ -15,6 +20,8 @@ impl FromBytes for u8 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         u8::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for u8 {
@@ -29,6 +36,8 @@ impl ToBytes for u8 {
     fn to_be_bytes(self) -> Self::Bytes {
         u8::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 impl FromBytes for i8 {
@@ -43,6 +52,8 @@ impl FromBytes for i8 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         i8::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for i8 {
@@ -57,6 +68,8 @@ impl ToBytes for i8 {
     fn to_be_bytes(self) -> Self::Bytes {
         i8::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 // 16-bit
@@ -73,6 +86,8 @@ impl FromBytes for u16 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         u16::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for u16 {
@@ -87,6 +102,8 @@ impl ToBytes for u16 {
     fn to_be_bytes(self) -> Self::Bytes {
         u16::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 impl FromBytes for i16 {
@@ -101,6 +118,8 @@ impl FromBytes for i16 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         i16::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for i16 {
@@ -115,6 +134,8 @@ impl ToBytes for i16 {
     fn to_be_bytes(self) -> Self::Bytes {
         i16::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 // 32-bit
@@ -131,6 +152,8 @@ impl FromBytes for u32 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         u32::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for u32 {
@@ -145,6 +168,8 @@ impl ToBytes for u32 {
     fn to_be_bytes(self) -> Self::Bytes {
         u32::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 impl FromBytes for i32 {
@@ -159,6 +184,8 @@ impl FromBytes for i32 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         i32::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for i32 {
@@ -173,6 +200,8 @@ impl ToBytes for i32 {
     fn to_be_bytes(self) -> Self::Bytes {
         i32::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 // 64-bit
@@ -189,6 +218,8 @@ impl FromBytes for u64 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         u64::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for u64 {
@@ -203,6 +234,8 @@ impl ToBytes for u64 {
     fn to_be_bytes(self) -> Self::Bytes {
         u64::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 impl FromBytes for i64 {
@@ -217,6 +250,8 @@ impl FromBytes for i64 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         i64::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for i64 {
@@ -231,6 +266,8 @@ impl ToBytes for i64 {
     fn to_be_bytes(self) -> Self::Bytes {
         i64::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 // 128-bit
@@ -247,6 +284,8 @@ impl FromBytes for u128 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         u128::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for u128 {
@@ -261,6 +300,8 @@ impl ToBytes for u128 {
     fn to_be_bytes(self) -> Self::Bytes {
         u128::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 impl FromBytes for i128 {
@@ -275,6 +316,8 @@ impl FromBytes for i128 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         i128::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for i128 {
@@ -289,6 +332,8 @@ impl ToBytes for i128 {
     fn to_be_bytes(self) -> Self::Bytes {
         i128::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 // Size
@@ -305,6 +350,8 @@ impl FromBytes for usize {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         usize::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for usize {
@@ -319,6 +366,8 @@ impl ToBytes for usize {
     fn to_be_bytes(self) -> Self::Bytes {
         usize::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 impl FromBytes for isize {
@@ -333,6 +382,8 @@ impl FromBytes for isize {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         isize::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for isize {
@@ -347,6 +398,8 @@ impl ToBytes for isize {
     fn to_be_bytes(self) -> Self::Bytes {
         isize::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 // Floats
@@ -363,6 +416,8 @@ impl FromBytes for f32 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         f32::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for f32 {
@@ -377,6 +432,8 @@ impl ToBytes for f32 {
     fn to_be_bytes(self) -> Self::Bytes {
         f32::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 impl FromBytes for f64 {
@@ -391,6 +448,8 @@ impl FromBytes for f64 {
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
         f64::from_be_bytes(bytes)
     }
+
+    decode_methods!();
 }
 
 impl ToBytes for f64 {
@@ -405,6 +464,8 @@ impl ToBytes for f64 {
     fn to_be_bytes(self) -> Self::Bytes {
         f64::to_be_bytes(self)
     }
+
+    encode_methods!();
 }
 
 // Char
@@ -502,3 +563,152 @@ impl ToBytes for () {
         []
     }
 }
+
+// Non-zero integers
+//
+// A non-zero integer can be only tried to be created from bytes, whereas an optional one
+// is created infallibly, with zero meaning `None`.
+
+macro_rules! nonzero_impls {
+    ($($nonzero:ident($int:ident)),* $(,)?) => {
+        $(
+            impl TryFromBytes for $nonzero {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+                type Error = InvalidNonZero;
+
+                #[inline]
+                fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, InvalidNonZero> {
+                    $nonzero::new($int::from_le_bytes(bytes)).ok_or(InvalidNonZero)
+                }
+
+                #[inline]
+                fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, InvalidNonZero> {
+                    $nonzero::new($int::from_be_bytes(bytes)).ok_or(InvalidNonZero)
+                }
+            }
+
+            impl ToBytes for $nonzero {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    self.get().to_le_bytes()
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    self.get().to_be_bytes()
+                }
+            }
+
+            impl FromBytes for Option<$nonzero> {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    $nonzero::new($int::from_le_bytes(bytes))
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    $nonzero::new($int::from_be_bytes(bytes))
+                }
+            }
+
+            impl ToBytes for Option<$nonzero> {
+                type Bytes = [u8; core::mem::size_of::<$int>()];
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    self.map_or(0, $nonzero::get).to_le_bytes()
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    self.map_or(0, $nonzero::get).to_be_bytes()
+                }
+            }
+        )*
+    };
+}
+
+nonzero_impls!(
+    NonZeroU8(u8),
+    NonZeroI8(i8),
+    NonZeroU16(u16),
+    NonZeroI16(i16),
+    NonZeroU32(u32),
+    NonZeroI32(i32),
+    NonZeroU64(u64),
+    NonZeroI64(i64),
+    NonZeroU128(u128),
+    NonZeroI128(i128),
+    NonZeroUsize(usize),
+    NonZeroIsize(isize),
+);
+
+// Wrapping and Saturating
+
+impl<T: FromBytes> FromBytes for Wrapping<T> {
+    type Bytes = T::Bytes;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Wrapping(T::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Wrapping(T::from_be_bytes(bytes))
+    }
+}
+
+impl<T: ToBytes> ToBytes for Wrapping<T> {
+    type Bytes = T::Bytes;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
+
+impl<T: FromBytes> FromBytes for Saturating<T> {
+    type Bytes = T::Bytes;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Saturating(T::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Saturating(T::from_be_bytes(bytes))
+    }
+}
+
+impl<T: ToBytes> ToBytes for Saturating<T> {
+    type Bytes = T::Bytes;
+
+    const PREFERS_LE: bool = T::PREFERS_LE;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
@@ -0,0 +1,654 @@
+use crate::error::{EncodeStrError, InvalidStr, StrTooLong};
+use crate::{ArrayBytes, FromBytes, ToBytes, TryFromBytes};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::{fmt, ops, str};
+
+/// A UTF-8 string stored in a buffer of `N` bytes, padded by the byte `PAD`.
+///
+/// By default, the string is padded by NUL bytes, such as `char[N]` fields in C structs:
+/// when reading, the string ends at the first NUL byte (or at the end of the buffer, if there is
+/// none) and the rest of the buffer is ignored.
+/// With any other padding, such as in [`FixedStrSpacePadded`], all the trailing padding bytes
+/// are removed instead.
+///
+/// Note that for this reason, strings containing NUL bytes or ending with the padding byte,
+/// respectively, are shortened when read back.
+///
+/// ```
+/// use packbytes::{FixedStr, FixedStrSpacePadded, ToBytes, TryFromBytes};
+///
+/// let name = FixedStr::<8>::try_from_bytes(*b"foo\0bar\0").unwrap();
+/// assert_eq!(name.as_str(), "foo");
+/// assert_eq!(name.to_bytes(), *b"foo\0\0\0\0\0");
+///
+/// let label = FixedStrSpacePadded::<6>::new("a b").unwrap();
+/// assert_eq!(label.to_bytes(), *b"a b   ");
+/// assert!(FixedStr::<2>::new("foo").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct FixedStr<const N: usize, const PAD: u8 = 0> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+/// A UTF-8 string stored in a buffer of `N` bytes, padded by spaces.
+///
+/// When reading, all the trailing spaces are removed.
+pub type FixedStrSpacePadded<const N: usize> = FixedStr<N, b' '>;
+
+impl<const N: usize, const PAD: u8> FixedStr<N, PAD> {
+    /// The largest length of a string in bytes that can be stored.
+    pub const CAPACITY: usize = N;
+
+    /// Create a fixed string from a string slice, returning an error if it is longer than `N` bytes.
+    pub fn new(s: &str) -> Result<Self, StrTooLong> {
+        if s.len() > N {
+            return Err(StrTooLong);
+        }
+        Ok(Self::new_truncated(s))
+    }
+
+    /// Create a fixed string from a string slice, cutting it to at most `N` bytes
+    /// (at a character boundary).
+    pub fn new_truncated(s: &str) -> Self {
+        let mut len = s.len().min(N);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut bytes = [PAD; N];
+        bytes[..len].copy_from_slice(&s.as_bytes()[..len]);
+        FixedStr { bytes, len }
+    }
+
+    /// Return the string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(self.as_bytes()).expect("the bytes of a fixed string are valid UTF-8")
+    }
+
+    /// Return the bytes of the string, without the padding.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Create a fixed string from characters, returning an error if they don't fit.
+    fn from_chars(chars: impl Iterator<Item = char>) -> Result<Self, StrTooLong> {
+        let mut bytes = [PAD; N];
+        let mut len = 0;
+        for c in chars {
+            let end = len + c.len_utf8();
+            if end > N {
+                return Err(StrTooLong);
+            }
+            c.encode_utf8(&mut bytes[len..end]);
+            len = end;
+        }
+        Ok(FixedStr { bytes, len })
+    }
+
+    /// Create a fixed string from the bytes of a buffer.
+    fn from_padded(mut bytes: [u8; N]) -> Result<Self, InvalidStr> {
+        let len = if PAD == 0 {
+            bytes.iter().position(|&b| b == 0).unwrap_or(N)
+        } else {
+            bytes.iter().rposition(|&b| b != PAD).map_or(0, |i| i + 1)
+        };
+        str::from_utf8(&bytes[..len]).map_err(|_| InvalidStr)?;
+        bytes[len..].fill(PAD);
+        Ok(FixedStr { bytes, len })
+    }
+}
+
+impl<const N: usize, const PAD: u8> Default for FixedStr<N, PAD> {
+    fn default() -> Self {
+        FixedStr {
+            bytes: [PAD; N],
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize, const PAD: u8> ops::Deref for FixedStr<N, PAD> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const PAD: u8> AsRef<str> for FixedStr<N, PAD> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const PAD: u8> TryFrom<&str> for FixedStr<N, PAD> {
+    type Error = StrTooLong;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, StrTooLong> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize, const PAD: u8> PartialEq for FixedStr<N, PAD> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize, const PAD: u8> Eq for FixedStr<N, PAD> {}
+
+impl<const N: usize, const PAD: u8> PartialEq<str> for FixedStr<N, PAD> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize, const PAD: u8> PartialEq<&str> for FixedStr<N, PAD> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize, const PAD: u8> PartialOrd for FixedStr<N, PAD> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, const PAD: u8> Ord for FixedStr<N, PAD> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize, const PAD: u8> Hash for FixedStr<N, PAD> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Debug for FixedStr<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Display for FixedStr<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const PAD: u8> TryFromBytes for FixedStr<N, PAD> {
+    type Bytes = [u8; N];
+    type Error = InvalidStr;
+
+    #[inline]
+    fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, InvalidStr> {
+        Self::from_padded(bytes)
+    }
+
+    #[inline]
+    fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, InvalidStr> {
+        Self::from_padded(bytes)
+    }
+}
+
+impl<const N: usize, const PAD: u8> ToBytes for FixedStr<N, PAD> {
+    type Bytes = [u8; N];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.bytes
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.bytes
+    }
+}
+
+/// An ASCII string stored in a buffer of `N` bytes, padded by the byte `PAD`.
+///
+/// It is the same as [`FixedStr`], except that reading fails with [`InvalidStr`] for any byte
+/// outside of ASCII, rather than only for invalid UTF-8. It dereferences to the string slice.
+///
+/// ```
+/// use packbytes::{AsciiStr, ToBytes, TryFromBytes};
+///
+/// let name = AsciiStr::<6>::try_from_bytes(*b"ID3\0\0\0").unwrap();
+/// assert_eq!(name, "ID3");
+/// assert_eq!(name.to_bytes(), *b"ID3\0\0\0");
+/// assert!(AsciiStr::<6>::try_from_bytes(*b"caf\xc3\xa9\0").is_err());
+/// assert!(AsciiStr::<6>::new("café").is_err());
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiStr<const N: usize, const PAD: u8 = 0>(FixedStr<N, PAD>);
+
+/// An ASCII string stored in a buffer of `N` bytes, padded by spaces.
+///
+/// When reading, all the trailing spaces are removed.
+pub type AsciiStrSpacePadded<const N: usize> = AsciiStr<N, b' '>;
+
+impl<const N: usize, const PAD: u8> AsciiStr<N, PAD> {
+    /// The largest length of a string in characters that can be stored.
+    pub const CAPACITY: usize = N;
+
+    /// Create an ASCII string from a string slice, returning an error if it is longer than `N`
+    /// characters or contains a character outside of ASCII.
+    pub fn new(s: &str) -> Result<Self, EncodeStrError> {
+        if !s.is_ascii() {
+            return Err(InvalidStr.into());
+        }
+        Ok(AsciiStr(FixedStr::new(s)?))
+    }
+
+    /// Return the string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Return the bytes of the string, without the padding.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Return the string as a UTF-8 fixed string.
+    #[inline]
+    pub fn into_fixed_str(self) -> FixedStr<N, PAD> {
+        self.0
+    }
+}
+
+impl<const N: usize, const PAD: u8> ops::Deref for AsciiStr<N, PAD> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const PAD: u8> AsRef<str> for AsciiStr<N, PAD> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const PAD: u8> TryFrom<&str> for AsciiStr<N, PAD> {
+    type Error = EncodeStrError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, EncodeStrError> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize, const PAD: u8> PartialEq<str> for AsciiStr<N, PAD> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize, const PAD: u8> PartialEq<&str> for AsciiStr<N, PAD> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Debug for AsciiStr<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Display for AsciiStr<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const PAD: u8> TryFromBytes for AsciiStr<N, PAD> {
+    type Bytes = [u8; N];
+    type Error = InvalidStr;
+
+    #[inline]
+    fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, InvalidStr> {
+        let s = FixedStr::from_padded(bytes)?;
+        if !s.as_bytes().is_ascii() {
+            return Err(InvalidStr);
+        }
+        Ok(AsciiStr(s))
+    }
+
+    #[inline]
+    fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, InvalidStr> {
+        Self::try_from_le_bytes(bytes)
+    }
+}
+
+impl<const N: usize, const PAD: u8> ToBytes for AsciiStr<N, PAD> {
+    type Bytes = [u8; N];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
+
+/// A UTF-16 string stored in a buffer of `N` code units, padded by zeros.
+///
+/// When reading, the string ends at the first zero code unit (or at the end of the buffer,
+/// if there is none). The code units follow the byte order of the containing struct.
+///
+/// The string can be converted to a UTF-8 [`FixedStr`] on the stack with `to_fixed_str`, or,
+/// with `std`, to a `String` with `to_string`.
+///
+/// ```
+/// use packbytes::{FromBytes, ToBytes, TryFromBytes, Utf16Str};
+///
+/// let name = Utf16Str::<4>::new("né").unwrap();
+/// let bytes = name.to_le_bytes();
+/// assert_eq!(bytes.as_ref(), [b'n', 0, 0xe9, 0, 0, 0, 0, 0]);
+/// assert_eq!(Utf16Str::<4>::try_from_le_bytes(bytes), Ok(name));
+/// assert_eq!(name.to_fixed_str::<3>().unwrap(), "né");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Utf16Str<const N: usize> {
+    units: [u16; N],
+    len: usize,
+}
+
+impl<const N: usize> Utf16Str<N> {
+    /// The largest length of a string in UTF-16 code units that can be stored.
+    pub const CAPACITY: usize = N;
+
+    /// Create a UTF-16 string from a string slice, returning an error if it is longer than
+    /// `N` code units.
+    pub fn new(s: &str) -> Result<Self, StrTooLong> {
+        let mut units = [0; N];
+        let mut len = 0;
+        for unit in s.encode_utf16() {
+            *units.get_mut(len).ok_or(StrTooLong)? = unit;
+            len += 1;
+        }
+        Ok(Utf16Str { units, len })
+    }
+
+    /// Return the UTF-16 code units of the string, without the padding.
+    #[inline]
+    pub fn as_units(&self) -> &[u16] {
+        &self.units[..self.len]
+    }
+
+    /// Return the length of the string in UTF-16 code units.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Is the string empty?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return an iterator over the characters of the string.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        // The validity is checked on creation, so this never fails.
+        char::decode_utf16(self.as_units().iter().copied()).map(|c| c.unwrap_or_default())
+    }
+
+    /// Convert the string to a UTF-8 string stored in a buffer of `M` bytes,
+    /// returning an error if it doesn't fit.
+    pub fn to_fixed_str<const M: usize>(&self) -> Result<FixedStr<M>, StrTooLong> {
+        FixedStr::from_chars(self.chars())
+    }
+
+    /// Create a UTF-16 string from the code units of a buffer.
+    fn from_padded(mut units: [u16; N]) -> Result<Self, InvalidStr> {
+        let len = units.iter().position(|&u| u == 0).unwrap_or(N);
+        if char::decode_utf16(units[..len].iter().copied()).any(|c| c.is_err()) {
+            return Err(InvalidStr);
+        }
+        units[len..].fill(0);
+        Ok(Utf16Str { units, len })
+    }
+}
+
+impl<const N: usize> Default for Utf16Str<N> {
+    fn default() -> Self {
+        Utf16Str {
+            units: [0; N],
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for Utf16Str<N> {
+    type Error = StrTooLong;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, StrTooLong> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> PartialEq<str> for Utf16Str<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for Utf16Str<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Utf16Str<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.chars() {
+            fmt::Display::fmt(&c.escape_debug(), f)?;
+        }
+        f.write_str("\"")
+    }
+}
+
+impl<const N: usize> fmt::Display for Utf16Str<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| fmt::Write::write_char(f, c))
+    }
+}
+
+impl<const N: usize> TryFromBytes for Utf16Str<N> {
+    type Bytes = ArrayBytes<[u8; 2], N>;
+    type Error = InvalidStr;
+
+    #[inline]
+    fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, InvalidStr> {
+        Self::from_padded(bytes.0.map(u16::from_le_bytes))
+    }
+
+    #[inline]
+    fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, InvalidStr> {
+        Self::from_padded(bytes.0.map(u16::from_be_bytes))
+    }
+}
+
+impl<const N: usize> ToBytes for Utf16Str<N> {
+    type Bytes = ArrayBytes<[u8; 2], N>;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        ArrayBytes(self.units.map(u16::to_le_bytes))
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        ArrayBytes(self.units.map(u16::to_be_bytes))
+    }
+}
+
+/// A Latin-1 (ISO 8859-1) string stored in a buffer of `N` bytes, padded by NUL bytes.
+///
+/// When reading, the string ends at the first NUL byte (or at the end of the buffer, if there is
+/// none). Since every byte is a valid Latin-1 character, reading never fails.
+///
+/// The string can be converted to a UTF-8 [`FixedStr`] on the stack with `to_fixed_str`, or,
+/// with `std`, to a `String` with `to_string`.
+///
+/// ```
+/// use packbytes::{FromBytes, Latin1Str};
+///
+/// let name = Latin1Str::<6>::from_bytes(*b"caf\xe9\0\0");
+/// assert_eq!(name, "café");
+/// assert_eq!(name.as_bytes(), b"caf\xe9");
+/// assert!(Latin1Str::<6>::new("€").is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Latin1Str<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Latin1Str<N> {
+    /// The largest length of a string in characters that can be stored.
+    pub const CAPACITY: usize = N;
+
+    /// Create a Latin-1 string from a string slice, returning an error if it is longer than `N`
+    /// characters or contains a character not representable in Latin-1.
+    pub fn new(s: &str) -> Result<Self, EncodeStrError> {
+        let mut bytes = [0; N];
+        let mut len = 0;
+        for c in s.chars() {
+            let byte = u8::try_from(c).map_err(|_| InvalidStr)?;
+            *bytes.get_mut(len).ok_or(StrTooLong)? = byte;
+            len += 1;
+        }
+        Ok(Latin1Str { bytes, len })
+    }
+
+    /// Return the Latin-1 bytes of the string, without the padding.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Return the length of the string in characters.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Is the string empty?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return an iterator over the characters of the string.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.as_bytes().iter().map(|&b| char::from(b))
+    }
+
+    /// Convert the string to a UTF-8 string stored in a buffer of `M` bytes,
+    /// returning an error if it doesn't fit.
+    pub fn to_fixed_str<const M: usize>(&self) -> Result<FixedStr<M>, StrTooLong> {
+        FixedStr::from_chars(self.chars())
+    }
+
+    /// Create a Latin-1 string from the bytes of a buffer.
+    fn from_padded(mut bytes: [u8; N]) -> Self {
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(N);
+        bytes[len..].fill(0);
+        Latin1Str { bytes, len }
+    }
+}
+
+impl<const N: usize> Default for Latin1Str<N> {
+    fn default() -> Self {
+        Latin1Str {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> PartialEq<str> for Latin1Str<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for Latin1Str<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Latin1Str<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.chars() {
+            fmt::Display::fmt(&c.escape_debug(), f)?;
+        }
+        f.write_str("\"")
+    }
+}
+
+impl<const N: usize> fmt::Display for Latin1Str<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| fmt::Write::write_char(f, c))
+    }
+}
+
+impl<const N: usize> FromBytes for Latin1Str<N> {
+    type Bytes = [u8; N];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Self::from_padded(bytes)
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self::from_padded(bytes)
+    }
+}
+
+impl<const N: usize> ToBytes for Latin1Str<N> {
+    type Bytes = [u8; N];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.bytes
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.bytes
+    }
+}
@@ -0,0 +1,164 @@
+use crate::error::{NotEnoughBytes, NotEnoughSpace};
+use core::mem::{size_of, size_of_val};
+
+/// Is the native byte order little endian?
+const NATIVE_LE: bool = cfg!(target_endian = "little");
+
+/// A primitive number, packed as its bytes in either byte order.
+pub(crate) trait Primitive: Copy {
+    /// Create the number from its bytes in the native byte order.
+    fn from_ne_chunk(chunk: &[u8]) -> Self;
+    /// Write the bytes of the number in the native byte order.
+    fn to_ne_chunk(self, chunk: &mut [u8]);
+    /// Return the number with the order of its bytes reversed.
+    fn swapped(self) -> Self;
+}
+
+macro_rules! primitives {
+    ($($int:ident),* ; $($float:ident),*) => {
+        $(
+            impl Primitive for $int {
+                primitives!(@chunks $int);
+
+                #[inline]
+                fn swapped(self) -> Self {
+                    self.swap_bytes()
+                }
+            }
+        )*
+        $(
+            impl Primitive for $float {
+                primitives!(@chunks $float);
+
+                #[inline]
+                fn swapped(self) -> Self {
+                    $float::from_bits(self.to_bits().swap_bytes())
+                }
+            }
+        )*
+    };
+    (@chunks $ty:ident) => {
+        #[inline]
+        fn from_ne_chunk(chunk: &[u8]) -> Self {
+            $ty::from_ne_bytes(chunk.try_into().expect("chunk of the size of the number"))
+        }
+
+        #[inline]
+        fn to_ne_chunk(self, chunk: &mut [u8]) {
+            chunk.copy_from_slice(&self.to_ne_bytes())
+        }
+    };
+}
+
+primitives!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize; f32, f64);
+
+/// Decode numbers packed one after another in the given byte order, filling the output slice.
+///
+/// The bytes are first copied in the native byte order. If the requested order is the other one,
+/// the numbers are then swapped in place.
+#[inline]
+pub(crate) fn decode<T: Primitive>(
+    bytes: &[u8],
+    out: &mut [T],
+    le: bool,
+) -> Result<usize, NotEnoughBytes> {
+    let needed = size_of_val(out);
+    let src = bytes.get(..needed).ok_or(NotEnoughBytes {
+        needed,
+        remaining: bytes.len(),
+    })?;
+    for (val, chunk) in out.iter_mut().zip(src.chunks_exact(size_of::<T>())) {
+        *val = T::from_ne_chunk(chunk);
+    }
+    if le != NATIVE_LE {
+        for val in out {
+            *val = val.swapped();
+        }
+    }
+    Ok(needed)
+}
+
+/// Encode numbers one after another in the given byte order at the beginning of the output slice.
+///
+/// The numbers are first written in the native byte order. If the requested order is the other
+/// one, the bytes of each number are then reversed in place.
+#[inline]
+pub(crate) fn encode<T: Primitive>(
+    values: &[T],
+    out: &mut [u8],
+    le: bool,
+) -> Result<usize, NotEnoughSpace> {
+    let size = size_of::<T>();
+    let needed = size_of_val(values);
+    let remaining = out.len();
+    let dst = out
+        .get_mut(..needed)
+        .ok_or(NotEnoughSpace { needed, remaining })?;
+    for (&val, chunk) in values.iter().zip(dst.chunks_exact_mut(size)) {
+        val.to_ne_chunk(chunk);
+    }
+    if le != NATIVE_LE {
+        for chunk in dst.chunks_exact_mut(size) {
+            chunk.reverse();
+        }
+    }
+    Ok(needed)
+}
+
+/// Implement the bulk methods of `FromBytes` by the functions of this module.
+macro_rules! decode_methods {
+    () => {
+        #[inline]
+        fn decode_le_into(
+            bytes: &[u8],
+            out: &mut [Self],
+        ) -> Result<usize, crate::error::NotEnoughBytes> {
+            crate::swap::decode(bytes, out, true)
+        }
+
+        #[inline]
+        fn decode_be_into(
+            bytes: &[u8],
+            out: &mut [Self],
+        ) -> Result<usize, crate::error::NotEnoughBytes> {
+            crate::swap::decode(bytes, out, false)
+        }
+
+        #[inline]
+        fn decode_le_array<const N: usize>(bytes: &[u8]) -> [Self; N] {
+            let mut out = [Self::default(); N];
+            crate::swap::decode(bytes, &mut out, true).expect("not enough bytes for the array");
+            out
+        }
+
+        #[inline]
+        fn decode_be_array<const N: usize>(bytes: &[u8]) -> [Self; N] {
+            let mut out = [Self::default(); N];
+            crate::swap::decode(bytes, &mut out, false).expect("not enough bytes for the array");
+            out
+        }
+    };
+}
+
+/// Implement the bulk methods of `ToBytes` by the functions of this module.
+macro_rules! encode_methods {
+    () => {
+        #[inline]
+        fn encode_le_slice(
+            values: &[Self],
+            out: &mut [u8],
+        ) -> Result<usize, crate::error::NotEnoughSpace> {
+            crate::swap::encode(values, out, true)
+        }
+
+        #[inline]
+        fn encode_be_slice(
+            values: &[Self],
+            out: &mut [u8],
+        ) -> Result<usize, crate::error::NotEnoughSpace> {
+            crate::swap::encode(values, out, false)
+        }
+    };
+}
+
+pub(crate) use {decode_methods, encode_methods};
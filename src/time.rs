@@ -0,0 +1,574 @@
+// Timestamps in common binary encodings
+//
+// Every type converts to and from `Duration` since its own epoch and, with `std`, to and from
+// `SystemTime`. Leap seconds are not accounted for.
+
+use crate::error::{InvalidData, OutOfRange};
+use crate::{FromBytes, ToBytes};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A number of seconds since the Unix epoch (1970-01-01 00:00:00 UTC), stored in 32 bits.
+///
+/// The largest representable time is in the year 2106.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTime32(pub u32);
+
+impl UnixTime32 {
+    /// Return the time elapsed since the Unix epoch.
+    #[inline]
+    pub const fn to_duration(self) -> Duration {
+        Duration::from_secs(self.0 as u64)
+    }
+
+    /// Create a time from the time elapsed since the Unix epoch, dropping the fractions
+    /// of a second. Returns `None` if it is out of range.
+    #[inline]
+    pub fn from_duration(duration: Duration) -> Option<Self> {
+        duration.as_secs().try_into().ok().map(UnixTime32)
+    }
+}
+
+/// An NTP timestamp: a 32.32 fixed-point number of seconds since 1900-01-01 00:00:00 UTC.
+///
+/// It is packed as a 64-bit integer, so in the big endian order (used by NTP), the seconds
+/// come before their fraction. Only the first NTP era (up to the year 2036) is supported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpTimestamp(pub u64);
+
+impl NtpTimestamp {
+    /// Create a timestamp from whole seconds and a fraction of a second in the units of 2^-32 s.
+    #[inline]
+    pub const fn new(seconds: u32, fraction: u32) -> Self {
+        NtpTimestamp(((seconds as u64) << 32) | fraction as u64)
+    }
+
+    /// Return the whole seconds since the epoch.
+    #[inline]
+    pub const fn seconds(self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Return the fraction of a second, in the units of 2^-32 s.
+    #[inline]
+    pub const fn fraction(self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Return the time elapsed since the NTP epoch, rounded down to nanoseconds.
+    #[inline]
+    pub const fn to_duration(self) -> Duration {
+        let nanos = (self.fraction() as u64 * 1_000_000_000) >> 32;
+        Duration::new(self.seconds() as u64, nanos as u32)
+    }
+
+    /// Create a timestamp from the time elapsed since the NTP epoch, rounding it down.
+    /// Returns `None` if it is out of range.
+    #[inline]
+    pub fn from_duration(duration: Duration) -> Option<Self> {
+        let seconds = duration.as_secs().try_into().ok()?;
+        let fraction = ((duration.subsec_nanos() as u64) << 32) / 1_000_000_000;
+        Some(Self::new(seconds, fraction as u32))
+    }
+}
+
+/// A Windows `FILETIME`: a number of 100 nanosecond intervals since 1601-01-01 00:00:00 UTC.
+///
+/// It is packed as a 64-bit integer, which in the little endian order (used by Windows) matches
+/// the `FILETIME` structure with the low part first.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileTime(pub u64);
+
+impl FileTime {
+    /// Return the time elapsed since the Windows epoch.
+    #[inline]
+    pub const fn to_duration(self) -> Duration {
+        Duration::new(self.0 / 10_000_000, (self.0 % 10_000_000) as u32 * 100)
+    }
+
+    /// Create a time from the time elapsed since the Windows epoch, rounding it down to
+    /// 100 nanoseconds. Returns `None` if it is out of range.
+    #[inline]
+    pub fn from_duration(duration: Duration) -> Option<Self> {
+        let intervals = duration.as_nanos() / 100;
+        intervals.try_into().ok().map(FileTime)
+    }
+}
+
+/// A GPS time: the week number since 1980-01-06 00:00:00 UTC, followed by the number of seconds
+/// within the week.
+///
+/// The week number is the full one, not truncated to 10 bits. GPS time does not count leap
+/// seconds, so it is ahead of UTC by the number of leap seconds since 1980 (18 in 2017).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GpsTime {
+    /// The number of weeks since the GPS epoch.
+    pub week: u16,
+    /// The number of seconds since the start of the week.
+    pub seconds: u32,
+}
+
+impl GpsTime {
+    /// The number of seconds in a week.
+    const WEEK: u64 = 7 * 24 * 60 * 60;
+
+    /// Return the time elapsed since the GPS epoch.
+    #[inline]
+    pub const fn to_duration(self) -> Duration {
+        Duration::from_secs(self.week as u64 * Self::WEEK + self.seconds as u64)
+    }
+
+    /// Create a time from the time elapsed since the GPS epoch, dropping the fractions
+    /// of a second. Returns `None` if it is out of range.
+    #[inline]
+    pub fn from_duration(duration: Duration) -> Option<Self> {
+        let secs = duration.as_secs();
+        Some(GpsTime {
+            week: (secs / Self::WEEK).try_into().ok()?,
+            seconds: (secs % Self::WEEK) as u32,
+        })
+    }
+}
+
+/// A date and time in the packed format used by MS-DOS, FAT file systems and ZIP archives:
+/// the time, followed by the date, both 16-bit integers.
+///
+/// The date holds the year since 1980 (7 bits), the month and the day; the time holds the hour,
+/// the minute and the second divided by two. It has no time zone, so it is usually local time;
+/// the conversions to `Duration` since 1980-01-01 and to `SystemTime` treat it as UTC.
+///
+/// ```
+/// use packbytes::{DosDateTime, FromBytes};
+///
+/// let time = DosDateTime::from_le_bytes([0x2b, 0x64, 0x92, 0x58]);
+/// assert_eq!((time.year(), time.month(), time.day()), (2024, 4, 18));
+/// assert_eq!((time.hour(), time.minute(), time.second()), (12, 33, 22));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DosDateTime {
+    /// The packed time.
+    pub time: u16,
+    /// The packed date.
+    pub date: u16,
+}
+
+impl DosDateTime {
+    /// Days between 0000-03-01 and 1980-01-01 in the proleptic Gregorian calendar.
+    const EPOCH_DAYS: u64 = 723_120;
+
+    /// Create a date and time from its parts, returning `None` if they are out of range.
+    /// The seconds are rounded down to an even number.
+    pub const fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Option<Self> {
+        if year < 1980
+            || year > 2107
+            || month == 0
+            || month > 12
+            || day == 0
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return None;
+        }
+        Some(DosDateTime {
+            time: (hour as u16) << 11 | (minute as u16) << 5 | (second as u16) >> 1,
+            date: (year - 1980) << 9 | (month as u16) << 5 | day as u16,
+        })
+    }
+
+    /// Return the year.
+    #[inline]
+    pub const fn year(self) -> u16 {
+        1980 + (self.date >> 9)
+    }
+
+    /// Return the month, starting from 1.
+    #[inline]
+    pub const fn month(self) -> u8 {
+        (self.date >> 5) as u8 & 0xf
+    }
+
+    /// Return the day of the month, starting from 1.
+    #[inline]
+    pub const fn day(self) -> u8 {
+        self.date as u8 & 0x1f
+    }
+
+    /// Return the hour.
+    #[inline]
+    pub const fn hour(self) -> u8 {
+        (self.time >> 11) as u8
+    }
+
+    /// Return the minute.
+    #[inline]
+    pub const fn minute(self) -> u8 {
+        (self.time >> 5) as u8 & 0x3f
+    }
+
+    /// Return the second (always even).
+    #[inline]
+    pub const fn second(self) -> u8 {
+        (self.time as u8 & 0x1f) * 2
+    }
+
+    /// Is every part of the date and time in its range?
+    pub const fn is_valid(self) -> bool {
+        Self::new(
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+        )
+        .is_some()
+    }
+
+    /// Return the time elapsed since 1980-01-01 00:00:00, or `None` if the date or time is invalid.
+    pub const fn to_duration(self) -> Option<Duration> {
+        if !self.is_valid() {
+            return None;
+        }
+        // Count the days from the March of the year 0, so that leap days are at the ends of years.
+        let (year, month) = if self.month() > 2 {
+            (self.year() as u64, self.month() as u64 - 3)
+        } else {
+            (self.year() as u64 - 1, self.month() as u64 + 9)
+        };
+        let days = year * 365 + year / 4 - year / 100
+            + year / 400
+            + (153 * month + 2) / 5
+            + self.day() as u64
+            - 1
+            - Self::EPOCH_DAYS;
+        let secs = self.hour() as u64 * 3600 + self.minute() as u64 * 60 + self.second() as u64;
+        Some(Duration::from_secs(days * 86400 + secs))
+    }
+
+    /// Create a date and time from the time elapsed since 1980-01-01 00:00:00, rounding it
+    /// down to two seconds. Returns `None` if it is out of range.
+    pub const fn from_duration(duration: Duration) -> Option<Self> {
+        let secs = duration.as_secs();
+        let days = secs / 86400 + Self::EPOCH_DAYS;
+        let secs = secs % 86400;
+        // The inverse of the computation in `to_duration`, by 400-year cycles.
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let (year, month) = if month < 10 {
+            (era * 400 + year_of_era, month + 3)
+        } else {
+            (era * 400 + year_of_era + 1, month - 9)
+        };
+        if year > 2107 {
+            return None;
+        }
+        Self::new(
+            year as u16,
+            month as u8,
+            day as u8,
+            (secs / 3600) as u8,
+            (secs / 60 % 60) as u8,
+            (secs % 60) as u8,
+        )
+    }
+}
+
+/// Return the number of days in the month of the year.
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Return the time of an epoch given by its offset in seconds from the Unix epoch.
+#[cfg(feature = "std")]
+fn epoch(offset: i64) -> SystemTime {
+    if offset >= 0 {
+        UNIX_EPOCH + Duration::from_secs(offset as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(offset.unsigned_abs())
+    }
+}
+
+/// Implement the conversions to and from `Duration` and `SystemTime` for a time type, given the
+/// offset of its epoch in seconds from the Unix epoch.
+macro_rules! time_impls {
+    ($($ty:ident($epoch:expr)),*) => {
+        $(
+            impl From<$ty> for Duration {
+                #[inline]
+                fn from(time: $ty) -> Self {
+                    time.to_duration()
+                }
+            }
+
+            impl TryFrom<Duration> for $ty {
+                type Error = OutOfRange;
+
+                #[inline]
+                fn try_from(duration: Duration) -> Result<Self, OutOfRange> {
+                    Self::from_duration(duration).ok_or(OutOfRange)
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl From<$ty> for SystemTime {
+                #[inline]
+                fn from(time: $ty) -> Self {
+                    epoch($epoch) + time.to_duration()
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl TryFrom<SystemTime> for $ty {
+                type Error = OutOfRange;
+
+                #[inline]
+                fn try_from(time: SystemTime) -> Result<Self, OutOfRange> {
+                    let duration = time.duration_since(epoch($epoch)).map_err(|_| OutOfRange)?;
+                    Self::try_from(duration)
+                }
+            }
+        )*
+    };
+}
+
+time_impls!(
+    UnixTime32(0),
+    NtpTimestamp(-2_208_988_800),
+    FileTime(-11_644_473_600),
+    GpsTime(315_964_800)
+);
+
+/// The offset of the DOS epoch (1980-01-01) from the Unix epoch, in seconds.
+#[cfg(feature = "std")]
+const DOS_EPOCH: i64 = 315_532_800;
+
+/// Fails if the date or time is invalid.
+impl TryFrom<DosDateTime> for Duration {
+    type Error = InvalidData;
+
+    #[inline]
+    fn try_from(time: DosDateTime) -> Result<Self, InvalidData> {
+        time.to_duration().ok_or(InvalidData)
+    }
+}
+
+impl TryFrom<Duration> for DosDateTime {
+    type Error = OutOfRange;
+
+    #[inline]
+    fn try_from(duration: Duration) -> Result<Self, OutOfRange> {
+        Self::from_duration(duration).ok_or(OutOfRange)
+    }
+}
+
+/// Fails if the date or time is invalid.
+#[cfg(feature = "std")]
+impl TryFrom<DosDateTime> for SystemTime {
+    type Error = InvalidData;
+
+    #[inline]
+    fn try_from(time: DosDateTime) -> Result<Self, InvalidData> {
+        Ok(epoch(DOS_EPOCH) + Duration::try_from(time)?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for DosDateTime {
+    type Error = OutOfRange;
+
+    #[inline]
+    fn try_from(time: SystemTime) -> Result<Self, OutOfRange> {
+        let duration = time
+            .duration_since(epoch(DOS_EPOCH))
+            .map_err(|_| OutOfRange)?;
+        Self::try_from(duration)
+    }
+}
+
+impl FromBytes for UnixTime32 {
+    type Bytes = [u8; 4];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        UnixTime32(u32::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        UnixTime32(u32::from_be_bytes(bytes))
+    }
+}
+
+impl ToBytes for UnixTime32 {
+    type Bytes = [u8; 4];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
+
+impl FromBytes for NtpTimestamp {
+    type Bytes = [u8; 8];
+
+    const PREFERS_LE: bool = false;
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        NtpTimestamp(u64::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        NtpTimestamp(u64::from_be_bytes(bytes))
+    }
+}
+
+impl ToBytes for NtpTimestamp {
+    type Bytes = [u8; 8];
+
+    const PREFERS_LE: bool = false;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
+
+impl FromBytes for FileTime {
+    type Bytes = [u8; 8];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        FileTime(u64::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        FileTime(u64::from_be_bytes(bytes))
+    }
+}
+
+impl ToBytes for FileTime {
+    type Bytes = [u8; 8];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
+
+impl FromBytes for GpsTime {
+    type Bytes = [u8; 6];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        GpsTime {
+            week: u16::from_le_bytes([bytes[0], bytes[1]]),
+            seconds: u32::from_le_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+        }
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        GpsTime {
+            week: u16::from_be_bytes([bytes[0], bytes[1]]),
+            seconds: u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+        }
+    }
+}
+
+impl ToBytes for GpsTime {
+    type Bytes = [u8; 6];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 6];
+        bytes[..2].copy_from_slice(&self.week.to_le_bytes());
+        bytes[2..].copy_from_slice(&self.seconds.to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 6];
+        bytes[..2].copy_from_slice(&self.week.to_be_bytes());
+        bytes[2..].copy_from_slice(&self.seconds.to_be_bytes());
+        bytes
+    }
+}
+
+impl FromBytes for DosDateTime {
+    type Bytes = [u8; 4];
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        DosDateTime {
+            time: u16::from_le_bytes([bytes[0], bytes[1]]),
+            date: u16::from_le_bytes([bytes[2], bytes[3]]),
+        }
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        DosDateTime {
+            time: u16::from_be_bytes([bytes[0], bytes[1]]),
+            date: u16::from_be_bytes([bytes[2], bytes[3]]),
+        }
+    }
+}
+
+impl ToBytes for DosDateTime {
+    type Bytes = [u8; 4];
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 4];
+        bytes[..2].copy_from_slice(&self.time.to_le_bytes());
+        bytes[2..].copy_from_slice(&self.date.to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        let mut bytes = [0; 4];
+        bytes[..2].copy_from_slice(&self.time.to_be_bytes());
+        bytes[2..].copy_from_slice(&self.date.to_be_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,362 @@
+use crate::error::InvalidData;
+use crate::{private, ByteArray, FromBytes, ToBytes, TryFromBytes, TryToBytes};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+use core::ops;
+
+/// A byte array made of two byte arrays, stored one after another.
+///
+/// Stable Rust cannot use `A::SIZE + B::SIZE` as a length of an array, so this type is used as
+/// the `Bytes` of tuples instead. Longer tuples nest it, e.g. the `Bytes` of `(u8, u16, u32)`
+/// is `ConcatBytes<[u8; 1], ConcatBytes<[u8; 2], [u8; 4]>>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct ConcatBytes<A, B>(pub A, pub B);
+
+impl<A: ByteArray, B: ByteArray> private::ByteArray for ConcatBytes<A, B> {}
+
+impl<A: ByteArray, B: ByteArray> ByteArray for ConcatBytes<A, B> {
+    const SIZE: usize = A::SIZE + B::SIZE;
+
+    fn zeroed() -> Self {
+        ConcatBytes(A::zeroed(), B::zeroed())
+    }
+}
+
+impl<A: ByteArray, B: ByteArray> AsRef<[u8]> for ConcatBytes<A, B> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        const { assert!(size_of::<Self>() == Self::SIZE && align_of::<Self>() == 1) };
+        // SAFETY: `ByteArray` is sealed and every type implementing it consists only of `SIZE`
+        // bytes with the alignment of 1, without any padding. Hence `Self`, having the C layout,
+        // has no padding between its fields either, as asserted above.
+        unsafe { core::slice::from_raw_parts((self as *const Self).cast(), Self::SIZE) }
+    }
+}
+
+impl<A: ByteArray, B: ByteArray> AsMut<[u8]> for ConcatBytes<A, B> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        const { assert!(size_of::<Self>() == Self::SIZE && align_of::<Self>() == 1) };
+        // SAFETY: see `as_ref`. Every sequence of bytes is a valid value of a byte array.
+        unsafe { core::slice::from_raw_parts_mut((self as *mut Self).cast(), Self::SIZE) }
+    }
+}
+
+impl<A: ByteArray, B: ByteArray> ops::Index<usize> for ConcatBytes<A, B> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        &self.as_ref()[index]
+    }
+}
+
+impl<A: ByteArray, B: ByteArray> ops::IndexMut<usize> for ConcatBytes<A, B> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl<A: ByteArray, B: ByteArray> ops::Index<ops::Range<usize>> for ConcatBytes<A, B> {
+    type Output = [u8];
+
+    #[inline]
+    fn index(&self, index: ops::Range<usize>) -> &[u8] {
+        &self.as_ref()[index]
+    }
+}
+
+impl<A: ByteArray, B: ByteArray> ops::IndexMut<ops::Range<usize>> for ConcatBytes<A, B> {
+    #[inline]
+    fn index_mut(&mut self, index: ops::Range<usize>) -> &mut [u8] {
+        &mut self.as_mut()[index]
+    }
+}
+
+// Tuples are packed by concatenating the packed representations of their elements.
+// The preferred byte order is little endian, like for the derived structs.
+// Like for array fields in the derive macros, `TryFromBytes` is provided only when every element
+// implements `FromBytes`. Tuples of elements that can fail to be unpacked use `TryTuple`.
+
+impl<A: FromBytes> FromBytes for (A,) {
+    type Bytes = A::Bytes;
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        (A::from_le_bytes(bytes),)
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        (A::from_be_bytes(bytes),)
+    }
+}
+
+impl<A: ToBytes> ToBytes for (A,) {
+    type Bytes = A::Bytes;
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+}
+
+/// Implement the traits for a tuple by splitting it into its first element and a shorter tuple.
+macro_rules! tuple_impls {
+    ($H:ident $h:ident, $($T:ident $t:ident),+) => {
+        impl<$H: FromBytes, $($T: FromBytes),+> FromBytes for ($H, $($T),+) {
+            type Bytes = ConcatBytes<$H::Bytes, <($($T,)+) as FromBytes>::Bytes>;
+
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                let ($($t,)+) = <($($T,)+)>::from_le_bytes(bytes.1);
+                ($H::from_le_bytes(bytes.0), $($t),+)
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                let ($($t,)+) = <($($T,)+)>::from_be_bytes(bytes.1);
+                ($H::from_be_bytes(bytes.0), $($t),+)
+            }
+        }
+
+        impl<$H: ToBytes, $($T: ToBytes),+> ToBytes for ($H, $($T),+) {
+            type Bytes = ConcatBytes<$H::Bytes, <($($T,)+) as ToBytes>::Bytes>;
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                let ($h, $($t),+) = self;
+                ConcatBytes($h.to_le_bytes(), ($($t,)+).to_le_bytes())
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                let ($h, $($t),+) = self;
+                ConcatBytes($h.to_be_bytes(), ($($t,)+).to_be_bytes())
+            }
+        }
+
+        tuple_impls!($($T $t),+);
+    };
+    ($H:ident $h:ident) => {};
+}
+
+tuple_impls!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l);
+
+/// A tuple of values which can fail to be unpacked or packed, packed by concatenating the
+/// packed representations of its elements.
+///
+/// Tuples implement `TryFromBytes` only when every element implements `FromBytes`: the blanket
+/// implementation of `TryFromBytes` for every `FromBytes` type covers those tuples, and another
+/// implementation for tuples with fallible elements would overlap with it. Thus a tuple such as
+/// `(Kind, u16)`, where `Kind` implements only `TryFromBytes`, can't be unpacked on its own.
+/// This wrapper implements `TryFromBytes` and `TryToBytes` for tuples of up to 12 elements
+/// implementing them.
+/// The error of the first element that fails is converted into `E` by `From`, like the errors of
+/// the fields of a derived struct. By default, `E` is [`InvalidData`]. The preferred byte order
+/// is little endian, like for tuples. It dereferences to the inner tuple.
+///
+/// ```
+/// use packbytes::error::InvalidData;
+/// use packbytes::{ConcatBytes, TryFromBytes, TryTuple};
+///
+/// type Pair = TryTuple<(bool, u16)>;
+/// assert_eq!(Pair::try_from_le_bytes(ConcatBytes([1], [2, 0])), Ok(TryTuple::new((true, 2))));
+/// assert_eq!(Pair::try_from_le_bytes(ConcatBytes([2], [2, 0])), Err(InvalidData));
+/// ```
+pub struct TryTuple<T, E = InvalidData>(pub T, PhantomData<fn() -> E>);
+
+// The traits are implemented by hand, so that they don't require anything of the error type.
+
+impl<T: fmt::Debug, E> fmt::Debug for TryTuple<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TryTuple").field(&self.0).finish()
+    }
+}
+
+impl<T: Clone, E> Clone for TryTuple<T, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        TryTuple::new(self.0.clone())
+    }
+}
+
+impl<T: Copy, E> Copy for TryTuple<T, E> {}
+
+impl<T: PartialEq, E> PartialEq for TryTuple<T, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, E> Eq for TryTuple<T, E> {}
+
+impl<T: PartialOrd, E> PartialOrd for TryTuple<T, E> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Ord, E> Ord for TryTuple<T, E> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Hash, E> Hash for TryTuple<T, E> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T, E> TryTuple<T, E> {
+    /// Wrap the tuple.
+    #[inline]
+    pub const fn new(tuple: T) -> Self {
+        TryTuple(tuple, PhantomData)
+    }
+
+    /// Return the inner tuple.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, E> ops::Deref for TryTuple<T, E> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, E> ops::DerefMut for TryTuple<T, E> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, E> From<T> for TryTuple<T, E> {
+    #[inline]
+    fn from(tuple: T) -> Self {
+        TryTuple::new(tuple)
+    }
+}
+
+impl<A: TryFromBytes, E> TryFromBytes for TryTuple<(A,), E>
+where
+    A::Error: Into<E>,
+{
+    type Bytes = A::Bytes;
+    type Error = E;
+
+    #[inline]
+    fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, E> {
+        Ok(TryTuple::new((
+            A::try_from_le_bytes(bytes).map_err(Into::into)?,
+        )))
+    }
+
+    #[inline]
+    fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, E> {
+        Ok(TryTuple::new((
+            A::try_from_be_bytes(bytes).map_err(Into::into)?,
+        )))
+    }
+}
+
+impl<A: TryToBytes, E> TryToBytes for TryTuple<(A,), E>
+where
+    A::Error: Into<E>,
+{
+    type Bytes = A::Bytes;
+    type Error = E;
+
+    #[inline]
+    fn try_to_le_bytes(self) -> Result<Self::Bytes, E> {
+        self.0 .0.try_to_le_bytes().map_err(Into::into)
+    }
+
+    #[inline]
+    fn try_to_be_bytes(self) -> Result<Self::Bytes, E> {
+        self.0 .0.try_to_be_bytes().map_err(Into::into)
+    }
+}
+
+/// Implement the fallible traits for a wrapped tuple by splitting it into its first element and
+/// a shorter wrapped tuple.
+macro_rules! try_tuple_impls {
+    ($H:ident $h:ident, $($T:ident $t:ident),+) => {
+        impl<$H: TryFromBytes, $($T: TryFromBytes),+, E> TryFromBytes for TryTuple<($H, $($T),+), E>
+        where
+            $H::Error: Into<E>,
+            $($T::Error: Into<E>),+
+        {
+            type Bytes = ConcatBytes<$H::Bytes, <TryTuple<($($T,)+), E> as TryFromBytes>::Bytes>;
+            type Error = E;
+
+            #[inline]
+            fn try_from_le_bytes(bytes: Self::Bytes) -> Result<Self, E> {
+                let $h = $H::try_from_le_bytes(bytes.0).map_err(Into::into)?;
+                let ($($t,)+) = TryTuple::<($($T,)+), E>::try_from_le_bytes(bytes.1)?.0;
+                Ok(TryTuple::new(($h, $($t),+)))
+            }
+
+            #[inline]
+            fn try_from_be_bytes(bytes: Self::Bytes) -> Result<Self, E> {
+                let $h = $H::try_from_be_bytes(bytes.0).map_err(Into::into)?;
+                let ($($t,)+) = TryTuple::<($($T,)+), E>::try_from_be_bytes(bytes.1)?.0;
+                Ok(TryTuple::new(($h, $($t),+)))
+            }
+        }
+
+        impl<$H: TryToBytes, $($T: TryToBytes),+, E> TryToBytes for TryTuple<($H, $($T),+), E>
+        where
+            $H::Error: Into<E>,
+            $($T::Error: Into<E>),+
+        {
+            type Bytes = ConcatBytes<$H::Bytes, <TryTuple<($($T,)+), E> as TryToBytes>::Bytes>;
+            type Error = E;
+
+            #[inline]
+            fn try_to_le_bytes(self) -> Result<Self::Bytes, E> {
+                let ($h, $($t),+) = self.0;
+                Ok(ConcatBytes(
+                    $h.try_to_le_bytes().map_err(Into::into)?,
+                    TryTuple::<($($T,)+), E>::new(($($t,)+)).try_to_le_bytes()?,
+                ))
+            }
+
+            #[inline]
+            fn try_to_be_bytes(self) -> Result<Self::Bytes, E> {
+                let ($h, $($t),+) = self.0;
+                Ok(ConcatBytes(
+                    $h.try_to_be_bytes().map_err(Into::into)?,
+                    TryTuple::<($($T,)+), E>::new(($($t,)+)).try_to_be_bytes()?,
+                ))
+            }
+        }
+
+        try_tuple_impls!($($T $t),+);
+    };
+    ($H:ident $h:ident) => {};
+}
+
+try_tuple_impls!(A a, B b, C c, D d, E2 e, F f, G g, H h, I i, J j, K k, L l);
@@ -1,6 +1,10 @@
 extern crate packbytes;
 use core::convert::Infallible;
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+use core::num::{NonZeroI16, NonZeroU32, Saturating, Wrapping};
+use core::time::Duration;
 use packbytes::*;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, FromBytes, ToBytes, Eq, PartialEq)]
 #[packbytes(be)]
@@ -9,7 +13,7 @@ struct Test {
     bar: u16,
 }
 
-#[derive(Debug, FromBytes, ToBytes, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, FromBytes, ToBytes, Eq, PartialEq)]
 struct Nameless(u16, i32);
 
 #[derive(Debug, FromBytes, ToBytes)]
@@ -57,6 +61,13 @@ struct WithArray {
     bar: u8,
 }
 
+#[derive(Debug, Clone, Copy, FromBytes, ToBytes, Eq, PartialEq)]
+#[packbytes(be)]
+struct WithPackedArray {
+    foo: u8,
+    arr: PackedArray<Nameless, 2>,
+}
+
 #[test]
 fn struct_test() {
     let bytes = [0x3, 0, 0, 0, 0x42, 0];
@@ -96,6 +107,21 @@ fn struct_test() {
     assert_eq!(<Unit as FromBytes>::Bytes::SIZE, 0);
 }
 
+#[test]
+fn blanket_try_order_test() {
+    let bytes = [0, 0, 0, 0x3, 0, 0x42];
+    assert_eq!(Test::try_from_bytes(bytes), Ok(Test::from_bytes(bytes)));
+    assert_eq!(
+        Test { foo: 3, bar: 0x42 }.try_to_bytes(),
+        Ok(Test { foo: 3, bar: 0x42 }.to_bytes())
+    );
+    assert_eq!(
+        Nameless::try_from_bytes(bytes),
+        Ok(Nameless::from_bytes(bytes))
+    );
+    assert_eq!(Nameless(1, 2).try_to_bytes(), Ok(Nameless(1, 2).to_bytes()));
+}
+
 #[test]
 fn enum_test() {
     assert_eq!(TestEnum::Foo.to_le_bytes(), [0x2]);
@@ -118,6 +144,17 @@ fn custom_error() {
         Err(CustomError::WrongTestEnum),
         TestEnum::try_from_le_bytes([0x3])
     );
+    assert_eq!(
+        Ok(ComplexStruct {
+            foo: 0x1,
+            e: TestEnum::Bar
+        }),
+        ComplexStruct::try_from_le_bytes([0x1, 0, 0, 0, 0x12])
+    );
+    assert_eq!(
+        Err(CustomError::WrongTestEnum),
+        ComplexStruct::try_from_le_bytes([0x1, 0, 0, 0, 0x13])
+    );
 }
 
 #[test]
@@ -141,3 +178,1474 @@ fn array_test() {
         .to_le_bytes()
     );
 }
+
+#[test]
+fn packed_array_test() {
+    let bytes = [0x1, 0, 0x2, 0, 0, 0, 0x3, 0, 0x4, 0, 0, 0x5];
+    let arr = PackedArray([Nameless(0x1, 0x2), Nameless(0x3, 0x5000004)]);
+    assert_eq!(<PackedArray<Nameless, 2> as FromBytes>::Bytes::SIZE, 12);
+    assert_eq!(
+        PackedArray::from_bytes(ArrayBytes(bytes.map(|b| [b]))),
+        PackedArray(bytes)
+    );
+    assert_eq!(
+        PackedArray::<Nameless, 2>::from_le_bytes(ArrayBytes([
+            [0x1, 0, 0x2, 0, 0, 0],
+            [0x3, 0, 0x4, 0, 0, 0x5]
+        ])),
+        arr
+    );
+    assert_eq!(arr.to_le_bytes().as_ref(), bytes);
+    assert_eq!(arr[1].0, 0x3);
+
+    type Nested = ArrayBytes<ConcatBytes<[u8; 1], ConcatBytes<[u8; 2], [u8; 4]>>, 3>;
+    assert_eq!(Nested::SIZE, 21);
+    assert_eq!(core::mem::size_of::<Nested>(), Nested::SIZE);
+    assert_eq!(core::mem::align_of::<Nested>(), 1);
+    let mut nested = Nested::zeroed();
+    nested.as_mut().copy_from_slice(&[0x5; 21]);
+    assert_eq!(nested.0[2].1 .1, [0x5; 4]);
+
+    assert_eq!(
+        TryPackedArray::<bool, 3>::try_from_bytes(ArrayBytes([[1], [0], [1]])),
+        Ok(TryPackedArray([true, false, true]))
+    );
+    assert_eq!(
+        TryPackedArray::<bool, 3>::try_from_bytes(ArrayBytes([[1], [2], [3]])),
+        Err(error::InvalidBool)
+    );
+    let enum_bytes = TryPackedArray([TestEnum::Bar, TestEnum::Foo])
+        .try_to_be_bytes()
+        .unwrap();
+    assert_eq!(enum_bytes.as_ref(), [0x12, 0x2]);
+    assert_eq!(
+        TryPackedArray::try_from_be_bytes(enum_bytes),
+        Ok(TryPackedArray([TestEnum::Bar, TestEnum::Foo]))
+    );
+    assert_eq!(
+        TryPackedArray::<TestEnum, 2>::try_from_le_bytes(ArrayBytes([[0x2], [0x3]])),
+        Err(CustomError::WrongTestEnum)
+    );
+
+    let mut reader = &bytes[..];
+    assert_eq!(
+        PackedArray::<u16, 6>::read_packed(&mut reader).unwrap(),
+        PackedArray([0x1, 0x2, 0, 0x3, 0x4, 0x500])
+    );
+
+    let with = WithPackedArray {
+        foo: 0x7,
+        arr: PackedArray([Nameless(0x1, 0x2), Nameless(0x3, 0x4)]),
+    };
+    let bebytes = [0x7, 0, 0x1, 0, 0, 0, 0x2, 0, 0x3, 0, 0, 0, 0x4];
+    assert_eq!(with.to_be_bytes(), bebytes);
+    assert_eq!(WithPackedArray::from_be_bytes(bebytes), with);
+}
+
+#[test]
+fn tuple_test() {
+    let bytes = [0x1, 0, 0x2, 0, 0, 0, 0x3];
+    assert_eq!(<(u16, u32, u8) as FromBytes>::Bytes::SIZE, 7);
+    assert_eq!((0x1u16, 0x2u32, 0x3u8).to_le_bytes().as_ref(), bytes);
+    assert_eq!(
+        (0x100u16, 0x2000000u32, 0x3u8).to_be_bytes().as_ref(),
+        bytes
+    );
+
+    let mut reader = &bytes[..];
+    assert_eq!(
+        <(u8, Nameless, ())>::read_packed(&mut reader).unwrap(),
+        (0x1, Nameless(0x200, 0x3000000), ())
+    );
+    assert_eq!(
+        <(u8,)>::try_from_le_bytes([0x4]),
+        Ok::<_, Infallible>((0x4,))
+    );
+
+    let mut reader = &[0x1, 0x12, 0, 0, 0, 0x2, 0][..];
+    assert_eq!(
+        reader
+            .try_read_packed::<TryTuple<(bool, FatTestEnum, u16)>>()
+            .unwrap(),
+        TryTuple::new((true, FatTestEnum::Bar, 0x2))
+    );
+    assert_eq!(
+        TryTuple::<(u8, TestEnum), CustomError>::try_from_bytes(ConcatBytes([0x1], [0x3])),
+        Err(CustomError::WrongTestEnum)
+    );
+    assert_eq!(
+        TryTuple::<(u8, bool)>::try_from_bytes(ConcatBytes([0x1], [0x3])),
+        Err(error::InvalidData)
+    );
+    let tuple: TryTuple<_> = (CheckedSensor {
+        level: 0.4,
+        flag: true,
+    },)
+        .into();
+    assert_eq!(tuple.try_to_le_bytes(), Ok([10, 0, 1]));
+    let tuple: TryTuple<_> = (
+        0x5u8,
+        CheckedSensor {
+            level: -20.0,
+            flag: false,
+        },
+    )
+        .into();
+    assert_eq!(tuple.try_to_le_bytes(), Err(error::InvalidData));
+
+    // The traits of the wrapper don't depend on the error type.
+    let mut set = std::collections::HashSet::new();
+    set.insert(TryTuple::<(bool, u16)>::new((true, 2)));
+    assert!(set.contains(&TryTuple::new((true, 2))));
+    assert!(TryTuple::<(u8,)>::new((1,)) < TryTuple::new((2,)));
+    let custom = TryTuple::<(u8, TestEnum), CustomError>::new((1, TestEnum::Foo));
+    assert_eq!(custom, TryTuple::new((1, TestEnum::Foo)));
+    let copied = TryTuple::<(u8, u16), CustomError>::new((1, 2));
+    assert_eq!(copied, copied.clone());
+}
+
+#[derive(Debug, TryFromBytes, ToBytes, Eq, PartialEq)]
+struct WithNonZero {
+    id: NonZeroU32,
+    parent: Option<NonZeroU32>,
+    counter: Wrapping<u8>,
+}
+
+#[test]
+fn nonzero_test() {
+    assert_eq!(
+        NonZeroI16::try_from_be_bytes([0x1, 0x2]),
+        Ok(NonZeroI16::new(0x102).unwrap())
+    );
+    assert_eq!(
+        NonZeroI16::try_from_le_bytes([0, 0]),
+        Err(error::InvalidNonZero)
+    );
+    assert_eq!(Option::<NonZeroI16>::from_le_bytes([0, 0]), None);
+    assert_eq!(None::<NonZeroI16>.to_be_bytes(), [0, 0]);
+    assert_eq!(Saturating(0x102u16).to_be_bytes(), [0x1, 0x2]);
+
+    let bytes = [0x1, 0, 0, 0, 0, 0, 0, 0, 0xff];
+    let with = WithNonZero {
+        id: NonZeroU32::new(1).unwrap(),
+        parent: None,
+        counter: Wrapping(0xff),
+    };
+    assert_eq!(WithNonZero::try_from_le_bytes(bytes), Ok(with));
+    assert_eq!(
+        WithNonZero::try_from_le_bytes([0; 9]),
+        Err(error::InvalidData)
+    );
+    assert_eq!(
+        WithNonZero::try_from_le_bytes(bytes).unwrap().to_le_bytes(),
+        bytes
+    );
+}
+
+#[derive(Debug, FromBytes, ToBytes, Eq, PartialEq)]
+struct PacketHeader {
+    src: SocketAddrV4,
+    dst: Ipv4Addr,
+}
+
+#[test]
+fn net_test() {
+    let header = PacketHeader {
+        src: SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 0x1234),
+        dst: Ipv4Addr::new(10, 0, 0, 1),
+    };
+    let bytes = [192, 168, 0, 1, 0x34, 0x12, 10, 0, 0, 1];
+    assert_eq!(PacketHeader::from_bytes(bytes), header);
+    assert_eq!(header.to_bytes(), bytes);
+
+    let addr = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0x1234, 0x5, 0x6);
+    let bytes = addr.to_be_bytes();
+    assert_eq!(bytes[..16], Ipv6Addr::LOCALHOST.octets());
+    assert_eq!(bytes[16..], [0x12, 0x34, 0, 0, 0, 0x5, 0, 0, 0, 0x6]);
+    assert_eq!(SocketAddrV6::from_be_bytes(bytes), addr);
+    assert_eq!(SocketAddrV6::from_le_bytes(addr.to_le_bytes()), addr);
+}
+
+#[derive(Debug, FromBytes, ToBytes, Eq, PartialEq)]
+#[packbytes(be)]
+struct Sample {
+    left: I24,
+    right: I24,
+    id: U48,
+}
+
+#[test]
+fn odd_int_test() {
+    assert_eq!(U24::MAX.get(), 0xffffff);
+    assert_eq!(I24::MIN.get(), -0x800000);
+    assert_eq!(I24::MAX.get(), 0x7fffff);
+    assert_eq!(I56::MIN.get(), -0x80000000000000);
+    assert_eq!(U24::new(0x1000000), None);
+    assert_eq!(I24::try_from(-0x800001), Err(error::OutOfRange));
+    assert_eq!(I24::new_wrapping(0xffffff).get(), -1);
+    assert_eq!(u64::from(U24::from(0x1234u16)), 0x1234);
+
+    assert_eq!(I24::from_le_bytes([0xfe, 0xff, 0xff]).get(), -2);
+    assert_eq!(I24::from_be_bytes([0x80, 0, 0]), I24::MIN);
+    assert_eq!(I24::new(-2).unwrap().to_be_bytes(), [0xff, 0xff, 0xfe]);
+    assert_eq!(
+        U40::new(0x102030405).unwrap().to_le_bytes(),
+        [5, 4, 3, 2, 1]
+    );
+
+    let bytes = [0xff, 0xff, 0xff, 0, 0, 0x1, 0, 0, 0, 0, 0x1, 0x2];
+    let sample = Sample {
+        left: I24::new(-1).unwrap(),
+        right: I24::new(1).unwrap(),
+        id: U48::new(0x102).unwrap(),
+    };
+    assert_eq!(Sample::from_bytes(bytes), sample);
+    assert_eq!(sample.to_bytes(), bytes);
+}
+
+#[derive(Debug, FromBytes, ToBytes, PartialEq)]
+struct Telemetry {
+    gain: Q15,
+    position: [Q16_16; 2],
+}
+
+#[test]
+fn fixed_test() {
+    let half = Q16_16::from_f32(0.5);
+    assert_eq!(half.to_bits(), 0x8000);
+    assert_eq!((half + half).to_f64(), 1.0);
+    assert_eq!((half - Q16_16::from_f64(2.0)).to_f32(), -1.5);
+    assert_eq!((half * Q16_16::from_f32(-3.0)).to_f32(), -1.5);
+    assert_eq!((half / Q16_16::from_f32(4.0)).to_f32(), 0.125);
+    assert_eq!((-half).to_bits(), -0x8000);
+    assert_eq!(Q15::from_f32(1.0), Q15::from_bits(i16::MAX));
+    assert_eq!(Q15::from_f32(-1.0).to_f32(), -1.0);
+    assert_eq!(Q31::from_f64(0.25).to_bits(), 0x20000000);
+    assert_eq!(Fixed::<u8, 4>::from_f32(1.53).to_bits(), 0x18);
+    assert_eq!(Fixed::<u8, 4>::from_f32(1.53).to_string(), "1.5");
+
+    let bytes = [0, 0x40, 0, 0x80, 0x1, 0, 0, 0, 0xff, 0xff];
+    let telemetry = Telemetry {
+        gain: Q15::from_f32(0.5),
+        position: [Q16_16::from_f32(1.5), Q16_16::from_f32(-1.0)],
+    };
+    assert_eq!(Telemetry::from_bytes(bytes), telemetry);
+    assert_eq!(telemetry.to_bytes(), bytes);
+}
+
+#[derive(Debug, FromBytes, ToBytes, PartialEq)]
+#[packbytes(be)]
+struct Sensor {
+    #[packbytes(repr = i16, scale = 0.01, offset = -40.0)]
+    temperature: f32,
+    #[packbytes(repr = u8, scale = 0.5)]
+    humidity: f64,
+    id: u8,
+}
+
+#[derive(Debug, TryFromBytes, TryToBytes, PartialEq)]
+struct CheckedSensor {
+    #[packbytes(repr = u16, offset = -10)]
+    level: f64,
+    flag: bool,
+}
+
+#[test]
+fn scaled_test() {
+    let sensor = Sensor {
+        temperature: 21.5,
+        humidity: 40.5,
+        id: 0x7,
+    };
+    let bytes = [0x18, 0x06, 81, 0x7];
+    assert_eq!(Sensor::from_bytes(bytes), sensor);
+    assert_eq!(sensor.to_bytes(), bytes);
+    let saturated = Sensor {
+        temperature: 1000.0,
+        humidity: -1.0,
+        id: 0,
+    };
+    assert_eq!(saturated.to_bytes(), [0x7f, 0xff, 0, 0]);
+
+    let checked = CheckedSensor {
+        level: 0.4,
+        flag: true,
+    };
+    assert_eq!(checked.try_to_bytes(), Ok([10, 0, 1]));
+    assert_eq!(
+        CheckedSensor::try_from_bytes([10, 0, 1]),
+        Ok(CheckedSensor {
+            level: 0.0,
+            flag: true
+        })
+    );
+    assert_eq!(
+        CheckedSensor {
+            level: -10.6,
+            flag: false
+        }
+        .try_to_le_bytes(),
+        Err(error::InvalidData)
+    );
+    assert_eq!(
+        CheckedSensor {
+            level: 65525.4,
+            flag: false
+        }
+        .try_to_be_bytes(),
+        Ok([0xff, 0xff, 0])
+    );
+    assert_eq!(
+        CheckedSensor {
+            level: f64::NAN,
+            flag: false
+        }
+        .try_to_be_bytes(),
+        Err(error::InvalidData)
+    );
+    assert_eq!(
+        0x1234u16.try_to_be_bytes(),
+        Ok::<_, Infallible>([0x12, 0x34])
+    );
+}
+
+#[derive(Debug, FromBytes, ToBytes, PartialEq)]
+struct TensorHeader {
+    scale: BF16,
+    values: [F16; 3],
+}
+
+#[test]
+fn half_float_test() {
+    assert_eq!(F16::from_f32(1.0).to_bits(), 0x3c00);
+    assert_eq!(F16::from_f32(-2.0).to_bits(), 0xc000);
+    assert_eq!(F16::from_f32(65504.0).to_bits(), 0x7bff);
+    assert_eq!(F16::from_f32(65520.0).to_bits(), 0x7c00);
+    assert_eq!(F16::from_f32(0.1).to_bits(), 0x2e66);
+    assert_eq!(F16::from_f32(5.960_464_5e-8).to_bits(), 0x1);
+    assert_eq!(F16::from_f32(2.0e-8).to_bits(), 0);
+    assert_eq!(F16::from_f32(1.0 + 1.0 / 2048.0).to_bits(), 0x3c00);
+    assert_eq!(F16::from_f32(1.0 + 3.0 / 2048.0).to_bits(), 0x3c02);
+    assert!(F16::from_f32(f32::NAN).is_nan());
+    assert_eq!(F16::from_bits(0x3555).to_f32(), 0.333_251_95);
+    assert_eq!(F16::MIN_POSITIVE.to_f64(), 2f64.powi(-14));
+    for bits in 0..=u16::MAX {
+        let half = F16::from_bits(bits);
+        if !half.is_nan() {
+            assert_eq!(F16::from_f32(half.to_f32()).to_bits(), bits);
+        }
+    }
+
+    assert_eq!(BF16::from_f32(1.0).to_bits(), 0x3f80);
+    assert_eq!(BF16::from_f32(core::f32::consts::PI).to_bits(), 0x4049);
+    assert_eq!(BF16::from_f32(f32::MAX), BF16::INFINITY);
+    assert!(BF16::from_f32(f32::NAN).is_nan());
+    assert_eq!(f32::from(BF16::from_bits(0xc0a0)), -5.0);
+    assert!(F16::NAN != F16::NAN);
+
+    let bytes = [0x80, 0x3f, 0, 0x3c, 0, 0xc0, 0xff, 0x7b];
+    let header = TensorHeader {
+        scale: BF16::from_f32(1.0),
+        values: [F16::from_f32(1.0), F16::from_f32(-2.0), F16::MAX],
+    };
+    assert_eq!(TensorHeader::from_bytes(bytes), header);
+    assert_eq!(header.to_bytes(), bytes);
+}
+
+#[derive(Debug, TryFromBytes, ToBytes, PartialEq)]
+struct DirEntry {
+    name: FixedStrSpacePadded<8>,
+    ext: FixedStrSpacePadded<3>,
+    comment: FixedStr<6>,
+}
+
+#[test]
+fn fixed_str_test() {
+    let bytes = *b"README  MD hi\0xyz";
+    let entry = DirEntry::try_from_bytes(bytes).unwrap();
+    assert_eq!(entry.name, "README");
+    assert_eq!(entry.ext.as_str(), "MD");
+    assert_eq!(&*entry.comment, "hi");
+    assert_eq!(entry.to_bytes(), *b"README  MD hi\0\0\0\0");
+
+    assert_eq!(
+        FixedStr::<4>::try_from_bytes([0xff, 0, 0, 0]),
+        Err(error::InvalidStr)
+    );
+    assert_eq!(
+        DirEntry::try_from_bytes(*b"\xffEADME  MD hi\0xyz"),
+        Err(error::InvalidData)
+    );
+    assert_eq!(FixedStr::<4>::try_from_bytes(*b"abcd").unwrap(), "abcd");
+    assert_eq!(
+        FixedStrSpacePadded::<4>::try_from_bytes(*b"    ").unwrap(),
+        ""
+    );
+    assert_eq!(FixedStr::<3>::new("abcd"), Err(error::StrTooLong));
+    assert_eq!(FixedStr::<3>::new_truncated("aé"), "aé");
+    assert_eq!(FixedStr::<2>::new_truncated("aé"), "a");
+    assert_eq!(FixedStr::<2>::new_truncated("aé").to_bytes(), *b"a\0");
+    assert_eq!(FixedStr::<5>::try_from("é").unwrap().len(), 2);
+
+    let tag = AsciiStrSpacePadded::<6>::try_from_bytes(*b"TAG   ").unwrap();
+    assert_eq!(tag, "TAG");
+    assert_eq!(tag.to_bytes(), *b"TAG   ");
+    assert_eq!(
+        AsciiStr::<4>::try_from_bytes(*b"\xc3\xa9\0\0"),
+        Err(error::InvalidStr)
+    );
+    assert_eq!(
+        AsciiStr::<4>::new("é"),
+        Err(error::EncodeStrError::Invalid(error::InvalidStr))
+    );
+    assert_eq!(
+        AsciiStr::<2>::new("abc"),
+        Err(error::EncodeStrError::TooLong(error::StrTooLong))
+    );
+}
+
+#[derive(Debug, TryFromBytes, ToBytes, PartialEq)]
+#[packbytes(be)]
+struct StringDescriptor {
+    length: u8,
+    text: Utf16Str<4>,
+    legacy: Latin1Str<4>,
+}
+
+#[test]
+fn utf16_latin1_test() {
+    let bytes = [0x8, 0, b'h', 0, b'i', 0xd8, 0x3d, 0xde, 0, b'p', 0xe9, 0, 0];
+    let descriptor = StringDescriptor::try_from_bytes(bytes).unwrap();
+    assert_eq!(descriptor.text, "hi😀");
+    assert_eq!(descriptor.text.len(), 4);
+    assert_eq!(descriptor.legacy, "pé");
+    assert_eq!(
+        descriptor.text.to_fixed_str::<6>().unwrap().as_str(),
+        "hi😀"
+    );
+    assert_eq!(descriptor.text.to_fixed_str::<5>(), Err(error::StrTooLong));
+    assert_eq!(descriptor.text.to_string(), "hi😀");
+    assert_eq!(format!("{:?}", descriptor.legacy), "\"pé\"");
+    assert_eq!(descriptor.to_bytes(), bytes);
+
+    assert_eq!(
+        Utf16Str::<2>::try_from_le_bytes(ArrayBytes([[0, 0xd8], [b'a', 0]])),
+        Err(error::InvalidStr)
+    );
+    assert_eq!(
+        Utf16Str::<2>::try_from_le_bytes(ArrayBytes([[b'a', 0], [0, 0xd8]])),
+        Err(error::InvalidStr)
+    );
+    assert_eq!(Utf16Str::<1>::new("😀"), Err(error::StrTooLong));
+    assert_eq!(
+        Latin1Str::<2>::new("abc"),
+        Err(error::EncodeStrError::TooLong(error::StrTooLong))
+    );
+    assert_eq!(
+        Latin1Str::<2>::new("€"),
+        Err(error::EncodeStrError::Invalid(error::InvalidStr))
+    );
+    assert_eq!(Latin1Str::<2>::new("é").unwrap().as_bytes(), [0xe9]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PackedFlags)]
+#[repr(u16)]
+#[packbytes(be)]
+#[packbytes_flags(FileFlags)]
+enum FileFlag {
+    Compressed = 0,
+    Encrypted = 3,
+}
+
+#[derive(Debug, PackedFlags)]
+#[repr(u8)]
+#[packbytes_flags(StrictFlags)]
+#[packbytes_strict]
+enum StrictFlag {
+    A,
+    B,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PackedFlags)]
+#[packbytes_repr(u8)]
+struct Permissions {
+    read: bool,
+    write: bool,
+    #[packbytes(bit = 7)]
+    sticky: bool,
+}
+
+#[derive(Debug, TryFromBytes, ToBytes, PartialEq)]
+#[packbytes(be)]
+struct FileHeader {
+    flags: FileFlags,
+    permissions: Permissions,
+}
+
+#[test]
+fn flags_test() {
+    let flags = FileFlags::from_bytes([0x00, 0x19]);
+    assert_eq!(
+        flags,
+        FileFlag::Compressed | FileFlag::Encrypted | FileFlags::from_bits_retain(0x10)
+    );
+    assert!(flags.contains(FileFlag::Encrypted));
+    assert!(!flags.is_all() || flags.contains(FileFlags::ALL));
+    assert_eq!(FileFlags::from_bits(0x19), None);
+    assert_eq!(FileFlags::from_bits_truncate(0x19), FileFlags::ALL);
+    assert_eq!(
+        flags.iter().collect::<Vec<_>>(),
+        [FileFlag::Compressed, FileFlag::Encrypted]
+    );
+    assert_eq!(
+        format!("{:?}", flags),
+        "FileFlags(Compressed | Encrypted | 0x10)"
+    );
+    assert_eq!(flags.to_bytes(), [0x00, 0x19]);
+
+    let mut flags = FileFlags::EMPTY;
+    flags.insert(FileFlag::Encrypted);
+    flags |= FileFlag::Compressed;
+    flags.remove(FileFlag::Compressed);
+    assert_eq!(flags.bits(), 0b1000);
+    assert_eq!(!flags, FileFlags::from(FileFlag::Compressed));
+    assert_eq!(flags - FileFlag::Encrypted, FileFlags::EMPTY);
+    assert_eq!(
+        [FileFlag::Compressed]
+            .into_iter()
+            .collect::<FileFlags>()
+            .bits(),
+        1
+    );
+
+    assert_eq!(
+        StrictFlags::try_from_bytes([0b11]).map(StrictFlags::bits),
+        Ok(0b11)
+    );
+    assert_eq!(
+        StrictFlags::try_from_bytes([0b100]),
+        Err(error::InvalidData)
+    );
+    assert_eq!(StrictFlags::from(StrictFlag::B).to_bytes(), [0b10]);
+
+    let header = FileHeader::try_from_bytes([0, 0x08, 0x83]).unwrap();
+    let permissions = Permissions {
+        read: true,
+        write: true,
+        sticky: true,
+    };
+    assert_eq!(header.permissions, permissions);
+    assert_eq!(Permissions::from_bits(0x40), None);
+    assert_eq!(Permissions::from_bits_truncate(0x41).bits(), 0x01);
+    let read = Permissions {
+        read: true,
+        ..Default::default()
+    };
+    assert_eq!((permissions - read).bits(), 0x82);
+    assert_eq!((!read).bits(), 0x82);
+    assert_eq!((read | Permissions::from_bits_truncate(2)).bits(), 0x03);
+    assert_eq!(header.to_bytes(), [0, 0x08, 0x83]);
+    assert_eq!(
+        Permissions::try_from_bytes([0x81]).map(Permissions::to_bytes),
+        Ok([0x81])
+    );
+    assert_eq!(Permissions::try_from_bytes([0x84]), Err(error::InvalidData));
+    assert_eq!(
+        FileHeader::try_from_bytes([0, 0x18, 0x83]).map(FileHeader::to_bytes),
+        Ok([0, 0x18, 0x83])
+    );
+}
+
+#[derive(Debug, TryFromBytes, ToBytes, PartialEq)]
+#[packbytes(be)]
+struct MidiMessage {
+    channel: Bounded<u8, 1, 16>,
+    pitch_bend: Bounded<i16, -8192, 8191>,
+}
+
+#[test]
+fn bounded_test() {
+    let message = MidiMessage::try_from_bytes([16, 0xe0, 0x00]).unwrap();
+    assert_eq!(message.channel.get(), 16);
+    assert_eq!(message.pitch_bend.get(), -8192);
+    assert_eq!(message.to_bytes(), [16, 0xe0, 0x00]);
+
+    assert_eq!(
+        MidiMessage::try_from_bytes([0, 0, 0]),
+        Err(error::InvalidData)
+    );
+    assert_eq!(
+        MidiMessage::try_from_bytes([1, 0x20, 0x00]),
+        Err(error::InvalidData)
+    );
+    assert_eq!(
+        Bounded::<u8, 1, 16>::try_from_bytes([17]),
+        Err(error::OutOfBounds {
+            value: 17,
+            min: 1,
+            max: 16
+        })
+    );
+    assert_eq!(
+        Bounded::<i16, -8192, 8191>::try_from_be_bytes([0xdf, 0xff]),
+        Err(error::OutOfBounds {
+            value: -8193,
+            min: -8192,
+            max: 8191
+        })
+    );
+    assert_eq!(
+        Bounded::<u32, 0, 100>::try_from(100).map(u32::from),
+        Ok(100)
+    );
+    assert_eq!(Bounded::<i64, -1, 1>::new(-2), None);
+}
+
+#[derive(Debug, Default, TryFromBytes, ToBytes, PartialEq)]
+#[packbytes(be)]
+struct Ipv4Header {
+    version_ihl: Const<u8, 0x45>,
+    tos: u8,
+    length: u16,
+}
+
+#[test]
+fn const_test() {
+    let header = Ipv4Header::try_from_bytes([0x45, 0, 0, 20]).unwrap();
+    assert_eq!(header.length, 20);
+    assert_eq!(header.version_ihl.get(), 0x45);
+    assert_eq!(format!("{:?}", header.version_ihl), "Const(69)");
+    assert_eq!(Ipv4Header::default().to_bytes(), [0x45, 0, 0, 0]);
+    assert_eq!(
+        Ipv4Header::try_from_bytes([0x46, 0, 0, 20]),
+        Err(error::InvalidData)
+    );
+    assert_eq!(
+        Const::<i32, -1>::try_from_le_bytes([0xff; 4]),
+        Ok(Const::new())
+    );
+    assert_eq!(
+        Const::<i32, -1>::try_from_le_bytes([0; 4]),
+        Err(error::InvalidConst)
+    );
+    assert_eq!(core::mem::size_of::<Ipv4Header>(), 4);
+}
+
+#[test]
+fn time_test() {
+    // 2024-04-18 12:33:22 UTC
+    let unix = Duration::from_secs(1_713_443_602);
+    let time = UNIX_EPOCH + unix;
+
+    let unix_time = UnixTime32::from_le_bytes(1_713_443_602u32.to_le_bytes());
+    assert_eq!(SystemTime::from(unix_time), time);
+    assert_eq!(UnixTime32::try_from(time), Ok(unix_time));
+    assert_eq!(
+        UnixTime32::try_from(UNIX_EPOCH - Duration::from_secs(1)),
+        Err(error::OutOfRange)
+    );
+
+    let ntp = NtpTimestamp::from_be_bytes([0xe9, 0xcb, 0x91, 0x92, 0x80, 0, 0, 0]);
+    assert_eq!(ntp.seconds(), 1_713_443_602 + 2_208_988_800);
+    assert_eq!(ntp.to_duration().subsec_millis(), 500);
+    assert_eq!(SystemTime::from(ntp), time + Duration::from_millis(500));
+    assert_eq!(
+        NtpTimestamp::try_from(time + Duration::from_millis(500)),
+        Ok(ntp)
+    );
+
+    let file_time = FileTime::from_le_bytes(133_579_172_020_000_001u64.to_le_bytes());
+    assert_eq!(
+        SystemTime::from(file_time),
+        time + Duration::from_nanos(100)
+    );
+    assert_eq!(
+        FileTime::try_from(time),
+        Ok(FileTime(133_579_172_020_000_000))
+    );
+
+    let gps = GpsTime::from_be_bytes([0x09, 0x06, 0x00, 0x05, 0xf6, 0x92]);
+    assert_eq!((gps.week, gps.seconds), (2310, 390_802));
+    assert_eq!(SystemTime::from(gps), time);
+    assert_eq!(GpsTime::try_from(time), Ok(gps));
+    assert_eq!(gps.to_be_bytes(), [0x09, 0x06, 0x00, 0x05, 0xf6, 0x92]);
+
+    let dos = DosDateTime::from_le_bytes([0x2b, 0x64, 0x92, 0x58]);
+    assert_eq!(SystemTime::try_from(dos), Ok(time));
+    assert_eq!(
+        DosDateTime::try_from(time + Duration::from_secs(1)),
+        Ok(dos)
+    );
+    assert_eq!(DosDateTime::new(2024, 4, 18, 12, 33, 23), Some(dos));
+    assert_eq!(DosDateTime::new(2023, 2, 29, 0, 0, 0), None);
+    assert_eq!(DosDateTime::default().to_duration(), None);
+    let leap_day = DosDateTime::new(2000, 2, 29, 23, 59, 58).unwrap();
+    assert_eq!(
+        DosDateTime::from_duration(leap_day.to_duration().unwrap()),
+        Some(leap_day)
+    );
+    assert_eq!(
+        DosDateTime::from_duration(Duration::ZERO).map(DosDateTime::to_bytes),
+        Some([0, 0, 0x21, 0])
+    );
+    assert_eq!(dos.to_bytes(), [0x2b, 0x64, 0x92, 0x58]);
+}
+
+#[derive(Debug, FromBytes, ToBytes, PartialEq)]
+struct GptPartitionEntry {
+    type_guid: Guid,
+    unique_guid: Guid,
+    first_lba: u64,
+}
+
+#[derive(Debug, Clone, Copy, FromBytes, ToBytes, PartialEq)]
+#[packbytes(be)]
+struct MixedGuids {
+    ms: MsGuid,
+    rfc: RfcUuid,
+}
+
+#[test]
+fn guid_test() {
+    let efi_system: Guid = "{C12A7328-F81F-11D2-BA4B-00A0C93EC93B}".parse().unwrap();
+    assert_eq!(
+        efi_system,
+        Guid::from_fields(
+            0xc12a7328,
+            0xf81f,
+            0x11d2,
+            [0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e, 0xc9, 0x3b]
+        )
+    );
+    let mut bytes = [0; 40];
+    bytes[..16].copy_from_slice(&[
+        0x28, 0x73, 0x2a, 0xc1, 0x1f, 0xf8, 0xd2, 0x11, 0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e, 0xc9,
+        0x3b,
+    ]);
+    bytes[32] = 0x22;
+    let entry = GptPartitionEntry::from_bytes(bytes);
+    assert_eq!(entry.type_guid, efi_system);
+    assert!(entry.unique_guid.is_nil());
+    assert_eq!(entry.first_lba, 0x22);
+    assert_eq!(entry.to_bytes(), bytes);
+
+    assert_eq!(
+        efi_system.to_be_bytes(),
+        0xc12a7328_f81f_11d2_ba4b_00a0c93ec93bu128.to_be_bytes()
+    );
+    assert_eq!(
+        format!("{:?}", Guid::NIL),
+        "00000000-0000-0000-0000-000000000000"
+    );
+    assert_eq!(
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93".parse::<Guid>(),
+        Err(error::ParseGuidError)
+    );
+    assert_eq!(
+        "c12a7328_f81f-11d2-ba4b-00a0c93ec93b".parse::<Guid>(),
+        Err(error::ParseGuidError)
+    );
+    assert_eq!(
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93g".parse::<Guid>(),
+        Err(error::ParseGuidError)
+    );
+
+    let mixed = MixedGuids {
+        ms: MsGuid(efi_system),
+        rfc: RfcUuid(efi_system),
+    };
+    let bytes = mixed.to_bytes();
+    assert_eq!(bytes[..16], efi_system.to_le_bytes());
+    assert_eq!(bytes[16..], efi_system.to_be_bytes());
+    assert_eq!(mixed.to_le_bytes(), bytes);
+    assert_eq!(MixedGuids::from_le_bytes(bytes), mixed);
+    assert_eq!(MixedGuids::from_be_bytes(bytes), mixed);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_test() {
+    let uuid = uuid::Uuid::parse_str("c12a7328-f81f-11d2-ba4b-00a0c93ec93b").unwrap();
+    let guid = Guid::from(uuid);
+    assert_eq!(guid.to_string(), uuid.to_string());
+    assert_eq!(guid.to_be_bytes(), *uuid.as_bytes());
+    assert_eq!(guid.to_le_bytes(), uuid.to_bytes_le());
+    assert_eq!(uuid::Uuid::from(guid), uuid);
+}
+
+#[test]
+fn slice_test() {
+    let input = [0x12, 0x34, 0x56, 0x78, 0x00, 0x02, 1, 0, 0, 0];
+    let (test, rest) = Test::from_slice_prefix(&input).unwrap();
+    assert_eq!(
+        test,
+        Test {
+            foo: 0x12345678,
+            bar: 2
+        }
+    );
+    let (value, rest) = u32::from_slice_prefix(rest).unwrap();
+    assert_eq!(value, 1);
+    assert!(rest.is_empty());
+    assert_eq!(Test::from_slice(&input[..6]), Ok(test));
+    assert_eq!(
+        Test::from_slice(&input[..7]),
+        Err(error::SliceError::TrailingBytes { extra: 1 })
+    );
+    assert_eq!(
+        Test::from_slice(&input[..5]),
+        Err(error::SliceError::NotEnoughBytes(error::NotEnoughBytes {
+            needed: 6,
+            remaining: 5
+        }))
+    );
+    assert_eq!(
+        u16::from_slice_prefix(&[1]),
+        Err(error::NotEnoughBytes {
+            needed: 2,
+            remaining: 1
+        })
+    );
+
+    let (test_enum, rest) = TestEnum::try_from_slice_prefix(&[0x12, 0xff]).unwrap();
+    assert_eq!((test_enum, rest), (TestEnum::Bar, &[0xff][..]));
+    assert_eq!(
+        TestEnum::try_from_slice_prefix(&[0x13]),
+        Err(error::SliceError::Invalid(CustomError::WrongTestEnum))
+    );
+    assert_eq!(
+        FatTestEnum::try_from_slice(&[1, 0]),
+        Err(error::SliceError::NotEnoughBytes(error::NotEnoughBytes {
+            needed: 4,
+            remaining: 2
+        }))
+    );
+    assert_eq!(
+        TestEnum::try_from_slice(&[0x12, 0xff]),
+        Err(error::SliceError::TrailingBytes { extra: 1 })
+    );
+    assert_eq!(u16::try_from_slice(&[1, 0]), Ok(1));
+    assert_eq!(
+        Test::try_from_slice(&input[..6]).map(|t| t.foo),
+        Ok(0x12345678)
+    );
+    assert_eq!(
+        error::SliceError::<error::InvalidData>::NotEnoughBytes(error::NotEnoughBytes {
+            needed: 4,
+            remaining: 2
+        })
+        .to_string(),
+        "not enough bytes, 4 bytes needed but 2 remaining"
+    );
+}
+
+#[test]
+fn decoder_test() {
+    let buffer = [
+        0x12, 0x34, 0x56, 0x78, 0x00, 0x02, 0x13, 0x00, 0x12, 0xaa, 0xbb,
+    ];
+    let mut decoder = Decoder::new(&buffer);
+    assert_eq!(
+        decoder.read::<Test>(),
+        Ok(Test {
+            foo: 0x12345678,
+            bar: 2
+        })
+    );
+    assert_eq!(
+        decoder.try_read::<TestEnum>(),
+        Err(error::SliceError::Invalid(CustomError::WrongTestEnum))
+    );
+    assert_eq!(decoder.position(), 6);
+    decoder.skip(1).unwrap();
+    assert!(!decoder.is_aligned(8));
+    decoder.align_to(8).unwrap();
+    assert_eq!(decoder.try_read::<TestEnum>(), Ok(TestEnum::Bar));
+    assert_eq!(
+        decoder.read::<u32>(),
+        Err(error::NotEnoughBytes {
+            needed: 4,
+            remaining: 2
+        })
+    );
+    assert_eq!(
+        decoder.try_peek::<u32>(),
+        Err(error::SliceError::NotEnoughBytes(error::NotEnoughBytes {
+            needed: 4,
+            remaining: 2
+        }))
+    );
+    assert_eq!(decoder.rest(), [0xaa, 0xbb]);
+    assert_eq!(decoder.read_bytes(2), Ok(&[0xaa, 0xbb][..]));
+    assert!(decoder.is_empty());
+    assert!(decoder.align_to(4).is_err());
+    assert_eq!(decoder.skip(0), Ok(()));
+}
+
+#[test]
+fn encoder_test() {
+    let mut buffer = [0xee; 16];
+    let mut encoder = Encoder::new(&mut buffer);
+    encoder.write(TestEnum::Foo).unwrap();
+    let length = encoder.reserve::<u16>().unwrap();
+    assert_eq!(length.position(), 1);
+    encoder.align_to(4).unwrap();
+    let start = encoder.position();
+    encoder
+        .write(Test {
+            foo: 0x12345678,
+            bar: 2,
+        })
+        .unwrap();
+    let len = (encoder.position() - start) as u16;
+    encoder.fill(length, len);
+    let mut other_buffer = [0; 16];
+    let mut other = Encoder::new(&mut other_buffer);
+    let other_length = other.reserve::<u16>().unwrap();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        encoder.fill(other_length, 0)
+    }));
+    assert!(result.is_err());
+    assert_eq!(encoder.remaining(), 6);
+    assert_eq!(
+        encoder.write(0u64),
+        Err(error::NotEnoughSpace {
+            needed: 8,
+            remaining: 6
+        })
+    );
+    assert!(encoder.write_bytes(&[0; 7]).is_err());
+    assert_eq!(encoder.position(), 10);
+    assert_eq!(
+        encoder.finish(),
+        [0x2, 6, 0, 0, 0x12, 0x34, 0x56, 0x78, 0x00, 0x02]
+    );
+}
+
+#[test]
+fn io_ext_test() {
+    let mut writer = Vec::new();
+    {
+        let writer: &mut dyn std::io::Write = &mut writer;
+        writer
+            .write_packed(Test {
+                foo: 0x12345678,
+                bar: 2,
+            })
+            .unwrap();
+        writer.write_packed_le(0x0102u16).unwrap();
+        writer.write_packed_be(0x0102u16).unwrap();
+        writer.try_write_packed(TestEnum::Bar).unwrap();
+    }
+    assert_eq!(
+        writer,
+        [0x12, 0x34, 0x56, 0x78, 0x00, 0x02, 0x02, 0x01, 0x01, 0x02, 0x12]
+    );
+
+    let mut reader = &writer[..];
+    let reader: &mut dyn std::io::Read = &mut reader;
+    assert_eq!(reader.read_packed::<Test>().unwrap().foo, 0x12345678);
+    assert_eq!(reader.read_packed_le::<u16>().unwrap(), 0x0102);
+    assert_eq!(reader.read_packed_be::<u16>().unwrap(), 0x0102);
+    let err = reader.try_read_packed::<Bounded<u8, 0, 1>>().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "the number 18 was out of the bounds 0..=1"
+    );
+    assert_eq!(
+        reader.read_packed::<u8>().unwrap_err().kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+
+    let mut writer = Vec::new();
+    let err = writer
+        .try_write_packed(CheckedSensor {
+            level: -100.0,
+            flag: true,
+        })
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+/// A reader returning at most 3 bytes at once, counting the reads.
+struct SlowReader<'a> {
+    bytes: &'a [u8],
+    reads: usize,
+}
+
+impl std::io::Read for SlowReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reads += 1;
+        let n = buf.len().min(3).min(self.bytes.len());
+        buf[..n].copy_from_slice(&self.bytes[..n]);
+        self.bytes = &self.bytes[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn iter_packed_test() {
+    let bytes = [0x12, 0x34, 0x56, 0x78, 0x00, 0x02, 0, 0, 0, 1, 0, 3];
+    let records: Vec<Test> = Test::iter_packed(&bytes[..])
+        .collect::<std::io::Result<_>>()
+        .unwrap();
+    assert_eq!(
+        records,
+        [
+            Test {
+                foo: 0x12345678,
+                bar: 2
+            },
+            Test { foo: 1, bar: 3 }
+        ]
+    );
+
+    let mut reader = SlowReader {
+        bytes: &bytes[..11],
+        reads: 0,
+    };
+    let mut iter = Test::iter_packed(&mut reader);
+    assert_eq!(iter.next().unwrap().unwrap().bar, 2);
+    let err = iter.next().unwrap().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    assert_eq!(
+        err.into_inner().unwrap().downcast_ref(),
+        Some(&error::NotEnoughBytes {
+            needed: 6,
+            remaining: 5
+        })
+    );
+    assert!(iter.next().is_none());
+
+    let mut reader = SlowReader {
+        bytes: &[1; 12],
+        reads: 0,
+    };
+    let mut iter = bool::try_iter_packed(&mut reader).read_ahead(4);
+    assert!(iter.next().unwrap().unwrap());
+    assert!(iter.next().unwrap().unwrap());
+    assert_eq!(iter.count(), 10);
+    assert_eq!(reader.reads, 5);
+
+    let mut iter = bool::try_iter_packed(&[1, 2, 1][..]);
+    assert!(iter.next().unwrap().is_ok());
+    assert_eq!(
+        iter.next().unwrap().unwrap_err().kind(),
+        std::io::ErrorKind::InvalidData
+    );
+    assert!(iter.next().is_none());
+
+    // Zero-sized values end the iteration at once, like `decode_slice`.
+    assert_eq!(<()>::iter_packed(&[][..]).take(3).count(), 0);
+    assert_eq!(<()>::iter_packed(&[1, 2][..]).take(3).count(), 0);
+    assert_eq!(<()>::decode_slice(&[1, 2]).count(), 0);
+}
+
+#[test]
+fn bulk_test() {
+    struct CountingWriter {
+        bytes: Vec<u8>,
+        writes: usize,
+    }
+
+    impl std::io::Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.writes += 1;
+            self.bytes.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let records: Vec<_> = (0..100_000i32).map(|i| Nameless(i as u16, -i)).collect();
+    let mut writer = CountingWriter {
+        bytes: Vec::new(),
+        writes: 0,
+    };
+    Nameless::write_packed_all(&records, &mut writer).unwrap();
+    assert_eq!(writer.bytes.len(), 600_000);
+    assert!(writer.writes < 100);
+    assert!(Nameless::decode_slice(&writer.bytes).eq(records.iter().copied()));
+
+    let mut decoded = vec![Nameless(0, 0); records.len()];
+    assert_eq!(
+        Nameless::decode_into(&writer.bytes, &mut decoded),
+        Ok(600_000)
+    );
+    assert_eq!(decoded, records);
+
+    let mut buf = vec![0; 600_000];
+    assert_eq!(Nameless::encode_slice(&records, &mut buf), Ok(600_000));
+    assert_eq!(buf, writer.bytes);
+    assert_eq!(
+        Nameless::encode_slice(&records, &mut buf[1..]),
+        Err(error::NotEnoughSpace {
+            needed: 600_000,
+            remaining: 599_999
+        })
+    );
+
+    let mut iter = Test::decode_slice(&[0, 0, 0, 1, 0, 2, 0, 0, 0, 3, 0]);
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(Test { foo: 1, bar: 2 }));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.remainder(), [0, 0, 0, 3, 0]);
+
+    let mut bytes = [0; 4];
+    assert_eq!(i8::encode_slice(&[-1, 2, -3], &mut bytes), Ok(3));
+    assert_eq!(bytes, [0xff, 2, 0xfd, 0]);
+    let mut values = [0i8; 4];
+    assert_eq!(i8::decode_into(&bytes, &mut values), Ok(4));
+    assert_eq!(values, [-1, 2, -3, 0]);
+    let mut values = [0u8; 5];
+    assert_eq!(
+        u8::decode_into(&bytes, &mut values),
+        Err(error::NotEnoughBytes {
+            needed: 5,
+            remaining: 4
+        })
+    );
+}
+
+#[test]
+fn byte_swap_test() {
+    #[derive(Debug, Clone, PartialEq, FromBytes, ToBytes)]
+    #[packbytes(be)]
+    struct FitsRow {
+        count: u16,
+        pixels: [u32; 37],
+        scale: [f32; 3],
+    }
+
+    let row = FitsRow {
+        count: 37,
+        pixels: core::array::from_fn(|i| 0x01020304 * i as u32),
+        scale: [1.5, -2.0, 0.25],
+    };
+    let bytes = row.clone().to_bytes();
+    assert_eq!(bytes[..2], [0, 37]);
+    assert_eq!(bytes[6..10], [0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(bytes[150..154], 1.5f32.to_be_bytes());
+    assert_eq!(FitsRow::from_bytes(bytes), row);
+
+    let values: Vec<u32> = (0..37u32).map(|i| i.wrapping_mul(0x9e3779b9)).collect();
+    let expected_be: Vec<u8> = values.iter().flat_map(|v| v.to_be_bytes()).collect();
+    let expected_le: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let mut buf = vec![0; 150];
+    assert_eq!(u32::encode_be_slice(&values, &mut buf), Ok(148));
+    assert_eq!(buf[..148], expected_be);
+    assert_eq!(u32::encode_le_slice(&values, &mut buf), Ok(148));
+    assert_eq!(buf[..148], expected_le);
+
+    let mut decoded = [0u32; 37];
+    assert_eq!(u32::decode_be_into(&expected_be, &mut decoded), Ok(148));
+    assert_eq!(decoded[..], values);
+    assert_eq!(u32::decode_le_into(&expected_le, &mut decoded), Ok(148));
+    assert_eq!(decoded[..], values);
+
+    let samples = [0.5f64, -1.25, f64::MAX, 3.0];
+    let mut buf = [0; 32];
+    assert_eq!(f64::encode_be_slice(&samples, &mut buf), Ok(32));
+    assert_eq!(buf[8..16], (-1.25f64).to_be_bytes());
+    let mut decoded = [0.0; 4];
+    assert_eq!(f64::decode_be_into(&buf, &mut decoded), Ok(32));
+    assert_eq!(decoded, samples);
+    assert_eq!(
+        i16::decode_be_into(&buf[..3], &mut [0; 2]),
+        Err(error::NotEnoughBytes {
+            needed: 4,
+            remaining: 3
+        })
+    );
+
+    let arr = PackedArray::from([1u16, 2, 3]);
+    assert_eq!(arr.to_be_bytes().as_ref(), [0, 1, 0, 2, 0, 3]);
+    let bytes = arr.to_be_bytes();
+    assert_eq!(*PackedArray::<u16, 3>::from_be_bytes(bytes), [1, 2, 3]);
+}
+
+#[cfg(any(
+    feature = "tokio",
+    feature = "futures-io",
+    feature = "embedded-io-async"
+))]
+fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+    struct NoopWaker;
+
+    impl std::task::Wake for NoopWaker {
+        fn wake(self: std::sync::Arc<Self>) {}
+    }
+
+    let waker = std::sync::Arc::new(NoopWaker).into();
+    let mut cx = core::task::Context::from_waker(&waker);
+    match core::pin::pin!(fut).poll(&mut cx) {
+        core::task::Poll::Ready(output) => output,
+        core::task::Poll::Pending => panic!("in-memory I/O should not block"),
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio_test() {
+    use packbytes::ext::tokio::{AsyncReadPackedExt, AsyncWritePackedExt};
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    let mut writer = Vec::new();
+    let fut = writer.write_packed_async(Test { foo: 1, bar: 2 });
+    assert_send(&fut);
+    block_on(fut).unwrap();
+    block_on(writer.write_packed_le_async(3u16)).unwrap();
+    block_on(writer.try_write_packed_async(true)).unwrap();
+    assert_eq!(writer, [0, 0, 0, 1, 0, 2, 3, 0, 1]);
+
+    let mut reader = &writer[..];
+    assert_eq!(
+        block_on(reader.read_packed_async::<Test>()).unwrap(),
+        Test { foo: 1, bar: 2 }
+    );
+    assert_eq!(
+        block_on(reader.read_packed_be_async::<u16>()).unwrap(),
+        0x300
+    );
+    assert!(block_on(reader.try_read_packed_async::<bool>()).unwrap());
+    assert_eq!(
+        block_on(reader.read_packed_async::<u8>())
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+
+    let mut reader = &[2u8][..];
+    assert_eq!(
+        block_on(reader.try_read_packed_async::<bool>())
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::InvalidData
+    );
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn futures_io_test() {
+    use packbytes::ext::futures::{AsyncReadPackedExt, AsyncWritePackedExt};
+
+    let mut writer = Vec::new();
+    block_on(writer.write_packed_async(Test { foo: 1, bar: 2 })).unwrap();
+    block_on(writer.write_packed_be_async(3u16)).unwrap();
+    block_on(writer.try_write_packed_le_async('a')).unwrap();
+    assert_eq!(writer, [0, 0, 0, 1, 0, 2, 0, 3, b'a', 0, 0, 0]);
+
+    let mut reader = &writer[..];
+    assert_eq!(
+        block_on(reader.read_packed_async::<Test>()).unwrap(),
+        Test { foo: 1, bar: 2 }
+    );
+    assert_eq!(
+        block_on(reader.read_packed_le_async::<u16>()).unwrap(),
+        0x300
+    );
+    assert_eq!(
+        block_on(reader.try_read_packed_async::<char>()).unwrap(),
+        'a'
+    );
+    assert_eq!(
+        block_on(reader.read_packed_async::<u8>())
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+}
+
+#[cfg(feature = "embedded-io")]
+mod embedded_io_test {
+    use super::{CheckedSensor, Test};
+    use embedded_io::{ErrorKind, ErrorType, Read, ReadExactError, Write};
+    use packbytes::error::{ReadPackedError, WritePackedError};
+    use packbytes::ext::embedded_io::{ReadPackedExt, WritePackedExt};
+
+    /// A serial port receiving and sending one byte at a time, with a limited send buffer.
+    struct Uart {
+        rx: Vec<u8>,
+        tx: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Overrun;
+
+    impl std::fmt::Display for Overrun {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "send buffer overrun")
+        }
+    }
+
+    impl std::error::Error for Overrun {}
+
+    impl embedded_io::Error for Overrun {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::OutOfMemory
+        }
+    }
+
+    impl ErrorType for Uart {
+        type Error = Overrun;
+    }
+
+    impl Read for Uart {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Overrun> {
+            if self.rx.is_empty() || buf.is_empty() {
+                return Ok(0);
+            }
+            buf[0] = self.rx.remove(0);
+            Ok(1)
+        }
+    }
+
+    impl Write for Uart {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Overrun> {
+            if self.tx.len() >= 8 {
+                return Err(Overrun);
+            }
+            self.tx.extend_from_slice(&buf[..1]);
+            Ok(1)
+        }
+
+        fn flush(&mut self) -> Result<(), Overrun> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "embedded-io-async")]
+    impl embedded_io_async::Read for Uart {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Overrun> {
+            Read::read(self, buf)
+        }
+    }
+
+    #[cfg(feature = "embedded-io-async")]
+    impl embedded_io_async::Write for Uart {
+        async fn write(&mut self, buf: &[u8]) -> Result<usize, Overrun> {
+            Write::write(self, buf)
+        }
+
+        async fn flush(&mut self) -> Result<(), Overrun> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn embedded_io_test() {
+        let mut uart = Uart {
+            rx: vec![0, 0, 0, 1, 0, 2, 3, 0, 2],
+            tx: Vec::new(),
+        };
+        assert_eq!(uart.read_packed::<Test>(), Ok(Test { foo: 1, bar: 2 }));
+        assert_eq!(uart.read_packed_le::<u16>(), Ok(3));
+        assert_eq!(
+            uart.try_read_packed::<bool>(),
+            Err(ReadPackedError::Invalid(packbytes::error::InvalidBool))
+        );
+        assert_eq!(uart.read_packed::<u8>(), Err(ReadExactError::UnexpectedEof));
+        assert_eq!(
+            uart.try_read_packed::<bool>(),
+            Err(ReadPackedError::UnexpectedEof)
+        );
+
+        uart.write_packed(Test { foo: 1, bar: 2 }).unwrap();
+        uart.try_write_packed_be(true).unwrap();
+        assert_eq!(uart.tx, [0, 0, 0, 1, 0, 2, 1]);
+        assert!(matches!(
+            uart.try_write_packed(CheckedSensor {
+                level: -20.0,
+                flag: false
+            }),
+            Err(WritePackedError::Invalid(_))
+        ));
+        assert_eq!(uart.write_packed_be(3u16), Err(Overrun));
+        assert_eq!(
+            uart.try_write_packed(0u16),
+            Err(WritePackedError::Io(Overrun))
+        );
+    }
+
+    #[cfg(feature = "embedded-io-async")]
+    #[test]
+    fn embedded_io_async_test() {
+        use super::block_on;
+        use packbytes::ext::embedded_io_async::{AsyncReadPackedExt, AsyncWritePackedExt};
+
+        let mut uart = Uart {
+            rx: vec![0, 0, 0, 1, 0, 2, 1],
+            tx: Vec::new(),
+        };
+        assert_eq!(
+            block_on(uart.read_packed_async::<Test>()),
+            Ok(Test { foo: 1, bar: 2 })
+        );
+        assert_eq!(block_on(uart.try_read_packed_async::<bool>()), Ok(true));
+        assert_eq!(
+            block_on(uart.read_packed_be_async::<u16>()),
+            Err(ReadExactError::UnexpectedEof)
+        );
+
+        block_on(uart.write_packed_le_async(0x0102u16)).unwrap();
+        block_on(uart.try_write_packed_async('a')).unwrap();
+        assert_eq!(uart.tx, [2, 1, b'a', 0, 0, 0]);
+        assert_eq!(
+            block_on(uart.try_write_packed_be_async(0u32)),
+            Err(WritePackedError::Io(Overrun))
+        );
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_test() {
+    use bytes::{Buf, BufMut, BytesMut};
+    use packbytes::ext::bytes::{BufMutPackedExt, BufPackedExt};
+
+    let mut buf = BytesMut::new();
+    buf.put_packed(Test { foo: 1, bar: 2 });
+    buf.put_packed_le(3u16);
+    buf.try_put_packed('a').unwrap();
+    assert!(buf
+        .try_put_packed(CheckedSensor {
+            level: -20.0,
+            flag: false
+        })
+        .is_err());
+    assert_eq!(buf, [0, 0, 0, 1, 0, 2, 3, 0, b'a', 0, 0, 0][..]);
+
+    // Values split across the chunks of a chained buffer.
+    let bytes = buf.freeze();
+    let mut chained = bytes.slice(..3).chain(bytes.slice(3..8)).chain(&[2, 1][..]);
+    assert_eq!(chained.get_packed::<Test>(), Test { foo: 1, bar: 2 });
+    assert_eq!(chained.get_packed_be::<u16>(), 3 << 8);
+    assert_eq!(
+        chained.try_get_packed::<bool>(),
+        Err(error::SliceError::Invalid(error::InvalidBool))
+    );
+    // The invalid byte is consumed, but too few bytes are left untouched.
+    assert_eq!(chained.remaining(), 1);
+    assert_eq!(
+        chained.try_get_packed_le::<u16>(),
+        Err(error::SliceError::NotEnoughBytes(error::NotEnoughBytes {
+            needed: 2,
+            remaining: 1
+        }))
+    );
+    assert_eq!(chained.remaining(), 1);
+    assert!(chained.try_get_packed_be::<bool>().unwrap());
+
+    let mut a = [0; 3];
+    let mut b = [0; 4];
+    let mut out = (&mut a[..]).chain_mut(&mut b[..]);
+    out.put_packed_be(0x01020304u32);
+    out.try_put_packed_le(0x0506u16).unwrap();
+    assert_eq!(out.remaining_mut(), 1);
+    assert_eq!((a, b), ([1, 2, 3], [4, 6, 5, 0]));
+}